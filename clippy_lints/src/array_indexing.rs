@@ -6,41 +6,41 @@ use utils::{self, higher};
 use utils::higher::Range;
 use consts::{constant, Constant};
 
-/// **What it does:** Checks for out of bounds array indexing with a constant
-/// index.
-///
-/// **Why is this bad?** This will always panic at runtime.
-///
-/// **Known problems:** Hopefully none.
-///
-/// **Example:**
-/// ```rust
-/// let x = [1,2,3,4];
-/// ...
-/// x[9];
-/// &x[2..9];
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for out of bounds array indexing with a constant
+    /// index.
+    ///
+    /// **Why is this bad?** This will always panic at runtime.
+    ///
+    /// **Known problems:** Hopefully none.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = [1,2,3,4];
+    /// ...
+    /// x[9];
+    /// &x[2..9];
+    /// ```
     pub OUT_OF_BOUNDS_INDEXING,
     correctness,
     "out of bounds constant indexing"
 }
 
-/// **What it does:** Checks for usage of indexing or slicing.
-///
-/// **Why is this bad?** Usually, this can be safely allowed. However, in some
-/// domains such as kernel development, a panic can cause the whole operating
-/// system to crash.
-///
-/// **Known problems:** Hopefully none.
-///
-/// **Example:**
-/// ```rust
-/// ...
-/// x[2];
-/// &x[0..2];
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of indexing or slicing.
+    ///
+    /// **Why is this bad?** Usually, this can be safely allowed. However, in some
+    /// domains such as kernel development, a panic can cause the whole operating
+    /// system to crash.
+    ///
+    /// **Known problems:** Hopefully none.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// ...
+    /// x[2];
+    /// &x[0..2];
+    /// ```
     pub INDEXING_SLICING,
     restriction,
     "indexing/slicing usage"
@@ -3,62 +3,62 @@ use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc::lint::*;
 use syntax::ast;
 use utils::{get_trait_def_id, implements_trait, snippet_opt, span_lint_and_then, SpanlessEq};
-use utils::{higher, sugg};
+use utils::{higher, sugg, Applicability, DiagnosticBuilderApplicabilityExt};
 
-/// **What it does:** Checks for compound assignment operations (`+=` and
-/// similar).
-///
-/// **Why is this bad?** Projects with many developers from languages without
-/// those operations may find them unreadable and not worth their weight.
-///
-/// **Known problems:** Types implementing `OpAssign` don't necessarily
-/// implement `Op`.
-///
-/// **Example:**
-/// ```rust
-/// a += 1;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for compound assignment operations (`+=` and
+    /// similar).
+    ///
+    /// **Why is this bad?** Projects with many developers from languages without
+    /// those operations may find them unreadable and not worth their weight.
+    ///
+    /// **Known problems:** Types implementing `OpAssign` don't necessarily
+    /// implement `Op`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// a += 1;
+    /// ```
     pub ASSIGN_OPS,
     restriction,
     "any compound assignment operation"
 }
 
-/// **What it does:** Checks for `a = a op b` or `a = b commutative_op a`
-/// patterns.
-///
-/// **Why is this bad?** These can be written as the shorter `a op= b`.
-///
-/// **Known problems:** While forbidden by the spec, `OpAssign` traits may have
-/// implementations that differ from the regular `Op` impl.
-///
-/// **Example:**
-/// ```rust
-/// let mut a = 5;
-/// ...
-/// a = a + b;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for `a = a op b` or `a = b commutative_op a`
+    /// patterns.
+    ///
+    /// **Why is this bad?** These can be written as the shorter `a op= b`.
+    ///
+    /// **Known problems:** While forbidden by the spec, `OpAssign` traits may have
+    /// implementations that differ from the regular `Op` impl.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut a = 5;
+    /// ...
+    /// a = a + b;
+    /// ```
     pub ASSIGN_OP_PATTERN,
     style,
     "assigning the result of an operation on a variable to that same variable"
 }
 
-/// **What it does:** Checks for `a op= a op b` or `a op= b op a` patterns.
-///
-/// **Why is this bad?** Most likely these are bugs where one meant to write `a
-/// op= b`.
-///
-/// **Known problems:** Someone might actually mean `a op= a op b`, but that
-/// should rather be written as `a = (2 * a) op b` where applicable.
-///
-/// **Example:**
-/// ```rust
-/// let mut a = 5;
-/// ...
-/// a += a + b;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for `a op= a op b` or `a op= b op a` patterns.
+    ///
+    /// **Why is this bad?** Most likely these are bugs where one meant to write `a
+    /// op= b`.
+    ///
+    /// **Known problems:** Someone might actually mean `a op= a op b`, but that
+    /// should rather be written as `a = (2 * a) op b` where applicable.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut a = 5;
+    /// ...
+    /// a += a + b;
+    /// ```
     pub MISREFACTORED_ASSIGN_OP,
     complexity,
     "having a variable on both sides of an assign op"
@@ -81,10 +81,11 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AssignOps {
                     let lhs = &sugg::Sugg::hir(cx, lhs, "..");
                     let rhs = &sugg::Sugg::hir(cx, rhs, "..");
 
-                    db.span_suggestion(
+                    db.span_suggestion_with_applicability(
                         expr.span,
                         "replace it with",
                         format!("{} = {}", lhs, sugg::make_binop(higher::binop(op.node), lhs, rhs)),
+                        Applicability::MachineApplicable,
                     );
                 });
                 if let hir::ExprBinary(binop, ref l, ref r) = rhs.node {
@@ -101,17 +102,19 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AssignOps {
                                     let a = &sugg::Sugg::hir(cx, assignee, "..");
                                     let r = &sugg::Sugg::hir(cx, rhs, "..");
                                     let long = format!("{} = {}", snip_a, sugg::make_binop(higher::binop(op.node), a, r));
-                                    db.span_suggestion(
+                                    db.span_suggestion_with_applicability(
                                         expr.span,
                                         &format!("Did you mean {} = {} {} {} or {}? Consider replacing it with",
                                                  snip_a, snip_a, op.node.as_str(), snip_r,
                                                  long),
-                                        format!("{} {}= {}", snip_a, op.node.as_str(), snip_r)
+                                        format!("{} {}= {}", snip_a, op.node.as_str(), snip_r),
+                                        Applicability::MaybeIncorrect
                                     );
-                                    db.span_suggestion(
+                                    db.span_suggestion_with_applicability(
                                         expr.span,
                                         "or",
-                                        long
+                                        long,
+                                        Applicability::MaybeIncorrect
                                     );
                                 },
                             );
@@ -192,10 +195,11 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AssignOps {
                                 |db| if let (Some(snip_a), Some(snip_r)) =
                                     (snippet_opt(cx, assignee.span), snippet_opt(cx, rhs.span))
                                 {
-                                    db.span_suggestion(
+                                    db.span_suggestion_with_applicability(
                                         expr.span,
                                         "replace it with",
                                         format!("{} {}= {}", snip_a, op.node.as_str(), snip_r),
+                                        Applicability::MachineApplicable,
                                     );
                                 },
                             );
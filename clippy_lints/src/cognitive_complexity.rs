@@ -0,0 +1,274 @@
+//! calculate the cognitive complexity of functions, how hard their control flow is to follow
+
+use rustc::hir::*;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use syntax::ast::{Attribute, NodeId};
+use syntax::codemap::Span;
+use utils::conf::Threshold;
+use utils::metrics::{self, Metric};
+use utils::{in_macro, item_header_span, opt_def_id, resolve_node, span_help_and_lint, LimitStack};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for functions with a high cognitive complexity.
+    /// Branches and loops cost more the deeper they are nested, `else` branches,
+    /// labeled `break` and `continue`, recursive calls and each change of operator
+    /// in a sequence of `&&` and `||` cost one more. A `match` costs the same
+    /// whatever its number of arms.
+    ///
+    /// **Why is this bad?** Deeply nested code and jumps in the control flow are
+    /// hard to follow for the readers, unlike the cyclomatic complexity this does
+    /// not penalise a flat `match` dispatching to other functions.
+    ///
+    /// **Known problems:** Sometimes it's hard to find a way to reduce the
+    /// complexity.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn find(rows: &[Vec<u32>], x: u32) -> Option<usize> {
+    ///     for (i, row) in rows.iter().enumerate() {   // +1
+    ///         for &y in row {                         // +2 (nesting = 1)
+    ///             if y == x && !row.is_empty() {      // +3 (nesting = 2), +1 for `&&`
+    ///                 return Some(i);
+    ///             }
+    ///         }
+    ///     }
+    ///     None
+    /// }
+    /// ```
+    pub COGNITIVE_COMPLEXITY,
+    nursery,
+    "functions whose control flow is too hard to follow"
+}
+
+pub struct CognitiveComplexity {
+    threshold: Threshold,
+    limit: LimitStack,
+}
+
+impl CognitiveComplexity {
+    pub fn new(threshold: Threshold) -> Self {
+        Self {
+            limit: LimitStack::new(threshold.default_value()),
+            threshold,
+        }
+    }
+}
+
+impl LintPass for CognitiveComplexity {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(COGNITIVE_COMPLEXITY)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CognitiveComplexity {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        // the closures are part of the function defining them
+        if let FnKind::Closure(_) = kind {
+            return;
+        }
+        let def_id = cx.tcx.hir.local_def_id(node_id);
+        if in_macro(span) || cx.tcx.has_attr(def_id, "test") {
+            return;
+        }
+
+        let mut helper = CognitiveHelper {
+            cx,
+            def_id,
+            nesting: 0,
+            score: 0,
+        };
+        helper.visit_expr(&body.value);
+        metrics::record(cx, node_id, Metric::CognitiveComplexity, helper.score);
+
+        // a `#[cognitive_complexity]` attribute wins over the configuration
+        let limit = if self.limit.is_overridden() {
+            self.limit.limit()
+        } else {
+            self.threshold.at(cx.sess().codemap(), span)
+        };
+        if helper.score > limit {
+            span_help_and_lint(
+                cx,
+                COGNITIVE_COMPLEXITY,
+                item_header_span(cx, span),
+                &format!("the function has a cognitive complexity of {}", helper.score),
+                "you could move the nested code to smaller functions, or return early",
+            );
+        }
+    }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.limit.push_attrs(cx.sess(), attrs, "cognitive_complexity");
+    }
+
+    fn exit_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        self.limit.pop_attrs(cx.sess(), attrs, "cognitive_complexity");
+    }
+}
+
+struct CognitiveHelper<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    /// The function checked, to find the recursive calls.
+    def_id: DefId,
+    /// The number of branches, loops and closures around the current expression.
+    nesting: u64,
+    score: u64,
+}
+
+impl<'a, 'tcx> CognitiveHelper<'a, 'tcx> {
+    /// A branch or a loop: one, plus one per level of nesting.
+    fn structure(&mut self) {
+        self.score += 1 + self.nesting;
+    }
+
+    fn visit_nested(&mut self, expr: &'tcx Expr) {
+        self.nesting += 1;
+        self.visit_expr(expr);
+        self.nesting -= 1;
+    }
+
+    /// An `if` or an `if let`, `else_if` if it is the `else` branch of another one and then costs
+    /// nothing for the nesting.
+    fn visit_if(&mut self, cond: &'tcx Expr, then: &'tcx Expr, els: Option<&'tcx Expr>, else_if: bool) {
+        if else_if {
+            self.score += 1;
+        } else {
+            self.structure();
+        }
+        self.visit_expr(cond);
+        self.visit_nested(then);
+        if let Some(els) = els {
+            match els.node {
+                ExprIf(ref cond, ref then, ref els) => self.visit_if(cond, then, els.as_ref().map(|e| &**e), true),
+                ExprMatch(ref scrutinee, ref arms, MatchSource::IfLetDesugar { contains_else_clause }) => {
+                    self.visit_if_let(scrutinee, arms, contains_else_clause, true)
+                },
+                _ => {
+                    self.score += 1;
+                    self.visit_nested(els);
+                },
+            }
+        }
+    }
+
+    fn visit_if_let(&mut self, scrutinee: &'tcx Expr, arms: &'tcx [Arm], has_else: bool, else_if: bool) {
+        // `if let P = e { a } else { b }` is `match e { P => a, _ => b }`
+        let els = if has_else {
+            arms.get(1).map(|arm| &*arm.body)
+        } else {
+            None
+        };
+        self.visit_if(scrutinee, &arms[0].body, els, else_if);
+    }
+
+    /// Each change of operator in a sequence of `&&` and `||` costs one, on top of one for the
+    /// sequence.
+    fn visit_bool_sequence(&mut self, expr: &'tcx Expr) {
+        let mut ops = Vec::new();
+        let mut operands = Vec::new();
+        flatten_bool_ops(expr, &mut ops, &mut operands);
+        self.score += 1 + ops.windows(2).filter(|w| w[0] != w[1]).count() as u64;
+        for operand in operands {
+            self.visit_expr(operand);
+        }
+    }
+
+    fn is_recursive_call(&self, expr: &'tcx Expr) -> bool {
+        match expr.node {
+            ExprCall(ref fun, _) => match fun.node {
+                ExprPath(ref qpath) => opt_def_id(resolve_node(self.cx, qpath, fun.hir_id)) == Some(self.def_id),
+                _ => false,
+            },
+            ExprMethodCall(..) => self.cx
+                .tables
+                .type_dependent_defs()
+                .get(expr.hir_id)
+                .map_or(false, |def| def.def_id() == self.def_id),
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for CognitiveHelper<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprIf(ref cond, ref then, ref els) => self.visit_if(cond, then, els.as_ref().map(|e| &**e), false),
+            ExprMatch(ref scrutinee, ref arms, source) => match source {
+                MatchSource::IfLetDesugar { contains_else_clause } => {
+                    self.visit_if_let(scrutinee, arms, contains_else_clause, false)
+                },
+                MatchSource::Normal => {
+                    self.structure();
+                    self.visit_expr(scrutinee);
+                    self.nesting += 1;
+                    for arm in arms {
+                        self.visit_arm(arm);
+                    }
+                    self.nesting -= 1;
+                },
+                // the loops are counted, and the `?` is not a branch for the readers
+                MatchSource::WhileLetDesugar | MatchSource::ForLoopDesugar | MatchSource::TryDesugar => {
+                    walk_expr(self, expr)
+                },
+            },
+            ExprWhile(ref cond, ref block, _) => {
+                self.structure();
+                self.visit_expr(cond);
+                self.nesting += 1;
+                self.visit_block(block);
+                self.nesting -= 1;
+            },
+            ExprLoop(ref block, _, _) => {
+                self.structure();
+                self.nesting += 1;
+                self.visit_block(block);
+                self.nesting -= 1;
+            },
+            ExprBreak(destination, _) | ExprAgain(destination) => {
+                if destination.label.is_some() {
+                    self.score += 1;
+                }
+                walk_expr(self, expr);
+            },
+            ExprBinary(op, _, _) if op.node == BiAnd || op.node == BiOr => self.visit_bool_sequence(expr),
+            ExprClosure(..) => {
+                self.nesting += 1;
+                walk_expr(self, expr);
+                self.nesting -= 1;
+            },
+            _ => {
+                if self.is_recursive_call(expr) {
+                    self.score += 1;
+                }
+                walk_expr(self, expr);
+            },
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
+
+/// The operators of a sequence of `&&` and `||` from left to right, and the operands which are not
+/// part of the sequence.
+fn flatten_bool_ops<'tcx>(expr: &'tcx Expr, ops: &mut Vec<BinOp_>, operands: &mut Vec<&'tcx Expr>) {
+    match expr.node {
+        ExprBinary(op, ref left, ref right) if op.node == BiAnd || op.node == BiOr => {
+            flatten_bool_ops(left, ops, operands);
+            ops.push(op.node);
+            flatten_bool_ops(right, ops, operands);
+        },
+        _ => operands.push(expr),
+    }
+}
@@ -7,32 +7,36 @@ use rustc::ty;
 use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use syntax::ast::{Attribute, NodeId};
 use syntax::codemap::Span;
+use utils::conf::Threshold;
+use utils::metrics::{self, Metric};
 
 use utils::{in_macro, is_allowed, match_type, paths, span_help_and_lint, LimitStack};
 
-/// **What it does:** Checks for methods with high cyclomatic complexity.
-///
-/// **Why is this bad?** Methods of high cyclomatic complexity tend to be badly
-/// readable. Also LLVM will usually optimize small methods better.
-///
-/// **Known problems:** Sometimes it's hard to find a way to reduce the
-/// complexity.
-///
-/// **Example:** No. You'll see it when you get the warning.
 declare_clippy_lint! {
+    /// **What it does:** Checks for methods with high cyclomatic complexity.
+    ///
+    /// **Why is this bad?** Methods of high cyclomatic complexity tend to be badly
+    /// readable. Also LLVM will usually optimize small methods better.
+    ///
+    /// **Known problems:** Sometimes it's hard to find a way to reduce the
+    /// complexity.
+    ///
+    /// **Example:** No. You'll see it when you get the warning.
     pub CYCLOMATIC_COMPLEXITY,
     complexity,
     "functions that should be split up into multiple functions"
 }
 
 pub struct CyclomaticComplexity {
+    threshold: Threshold,
     limit: LimitStack,
 }
 
 impl CyclomaticComplexity {
-    pub fn new(limit: u64) -> Self {
+    pub fn new(threshold: Threshold) -> Self {
         Self {
-            limit: LimitStack::new(limit),
+            limit: LimitStack::new(threshold.default_value()),
+            threshold,
         }
     }
 }
@@ -44,7 +48,7 @@ impl LintPass for CyclomaticComplexity {
 }
 
 impl CyclomaticComplexity {
-    fn check<'a, 'tcx: 'a>(&mut self, cx: &'a LateContext<'a, 'tcx>, body: &'tcx Body, span: Span) {
+    fn check<'a, 'tcx: 'a>(&mut self, cx: &'a LateContext<'a, 'tcx>, body: &'tcx Body, span: Span, node_id: NodeId) {
         if in_macro(span) {
             return;
         }
@@ -53,11 +57,9 @@ impl CyclomaticComplexity {
         let expr = &body.value;
         let n = cfg.graph.len_nodes() as u64;
         let e = cfg.graph.len_edges() as u64;
-        if e + 2 < n {
-            // the function has unreachable code, other lints should catch this
-            return;
-        }
-        let cc = e + 2 - n;
+        // the function has unreachable code, other lints should catch this
+        let unreachable = e + 2 < n;
+        let cc = (e + 2).saturating_sub(n);
         let mut helper = CCHelper {
             match_arms: 0,
             divergence: 0,
@@ -80,6 +82,17 @@ impl CyclomaticComplexity {
             returns / 2
         };
 
+        let mut rust_cc = (cc + divergence).saturating_sub(match_arms + short_circuits);
+        // prevent degenerate cases where unreachable code contains `return` statements
+        if rust_cc >= ret_adjust {
+            rust_cc -= ret_adjust;
+        }
+        // the function is measured even if it is not linted, so every function is in the report
+        metrics::record(cx, node_id, Metric::CyclomaticComplexity, rust_cc);
+        if unreachable {
+            return;
+        }
+
         if cc + divergence < match_arms + short_circuits {
             report_cc_bug(
                 cx,
@@ -92,12 +105,13 @@ impl CyclomaticComplexity {
                 body.id().node_id,
             );
         } else {
-            let mut rust_cc = cc + divergence - match_arms - short_circuits;
-            // prevent degenerate cases where unreachable code contains `return` statements
-            if rust_cc >= ret_adjust {
-                rust_cc -= ret_adjust;
-            }
-            if rust_cc > self.limit.limit() {
+            // a `#[cyclomatic_complexity]` attribute wins over the configuration
+            let limit = if self.limit.is_overridden() {
+                self.limit.limit()
+            } else {
+                self.threshold.at(cx.sess().codemap(), span)
+            };
+            if rust_cc > limit {
                 span_help_and_lint(
                     cx,
                     CYCLOMATIC_COMPLEXITY,
@@ -122,7 +136,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CyclomaticComplexity {
     ) {
         let def_id = cx.tcx.hir.local_def_id(node_id);
         if !cx.tcx.has_attr(def_id, "test") {
-            self.check(cx, body, span);
+            self.check(cx, body, span, node_id);
         }
     }
 
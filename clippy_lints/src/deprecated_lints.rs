@@ -1,6 +1,14 @@
 macro_rules! declare_deprecated_lint {
-    (pub $name: ident, $_reason: expr) => {
-        declare_lint!(pub $name, Allow, "deprecated lint")
+    (pub $name: ident, $reason: expr) => {
+        declare_lint!(pub $name, Allow, "deprecated lint");
+
+        #[allow(non_snake_case)]
+        pub mod $name {
+            pub static INFO: ::utils::metadata::DeprecatedLint = ::utils::metadata::DeprecatedLint {
+                lint: &super::$name,
+                reason: $reason,
+            };
+        }
     }
 }
 
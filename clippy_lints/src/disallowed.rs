@@ -0,0 +1,176 @@
+use rustc::hir::*;
+use rustc::hir::def_id::DefId;
+use rustc::lint::*;
+use std::collections::HashMap;
+use syntax::codemap::Span;
+use utils::conf::DisallowedPath;
+use utils::{in_external_macro, opt_def_id, path_to_def, resolve_node, span_lint_and_then};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for uses of the functions and methods listed in the
+    /// `disallowed-methods` configuration.
+    ///
+    /// **Why is this bad?** Some functions are banned by the project, eg. because
+    /// they are not thread safe or because a wrapper must be used instead.
+    ///
+    /// **Known problems:** Calls through a trait object or a generic parameter are
+    /// only found if the trait method itself is disallowed.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// disallowed-methods = [
+    ///     "std::env::set_var",
+    ///     { path = "chrono::Local::now", reason = "use the clock of the context" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust
+    /// std::env::set_var("RUST_LOG", "debug");
+    /// ```
+    pub DISALLOWED_METHODS,
+    style,
+    "use of a function or method disallowed in the configuration"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for uses of the types listed in the
+    /// `disallowed-types` configuration, in types, struct expressions and imports.
+    ///
+    /// **Why is this bad?** Some types are banned by the project, eg. because a
+    /// faster or deterministic alternative must be used instead.
+    ///
+    /// **Known problems:** Types that are only inferred are not found.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// disallowed-types = [
+    ///     { path = "std::collections::HashMap", reason = "use `FxHashMap`" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// ```
+    pub DISALLOWED_TYPES,
+    style,
+    "use of a type disallowed in the configuration"
+}
+
+pub struct DisallowedPaths {
+    methods: Vec<DisallowedPath>,
+    types: Vec<DisallowedPath>,
+    /// The resolved `methods`, with their index.
+    method_ids: HashMap<DefId, usize>,
+    /// The resolved `types`, with their index.
+    type_ids: HashMap<DefId, usize>,
+}
+
+impl DisallowedPaths {
+    pub fn new(methods: Vec<DisallowedPath>, types: Vec<DisallowedPath>) -> Self {
+        Self {
+            methods,
+            types,
+            method_ids: HashMap::new(),
+            type_ids: HashMap::new(),
+        }
+    }
+
+    fn check_method(&self, cx: &LateContext, def_id: DefId, span: Span) {
+        if let Some(&i) = self.method_ids.get(&def_id) {
+            report(cx, DISALLOWED_METHODS, "method", &self.methods[i], span);
+        }
+    }
+
+    fn check_type(&self, cx: &LateContext, def_id: DefId, span: Span) {
+        if let Some(&i) = self.type_ids.get(&def_id) {
+            report(cx, DISALLOWED_TYPES, "type", &self.types[i], span);
+        }
+    }
+}
+
+impl LintPass for DisallowedPaths {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DISALLOWED_METHODS, DISALLOWED_TYPES)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DisallowedPaths {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        // paths of crates the current crate does not depend on do not resolve and are ignored
+        for (i, path) in self.methods.iter().enumerate() {
+            if let Some(def_id) = resolve(cx, &path.segments()) {
+                self.method_ids.insert(def_id, i);
+            }
+        }
+        for (i, path) in self.types.iter().enumerate() {
+            if let Some(def_id) = resolve(cx, &path.segments()) {
+                self.type_ids.insert(def_id, i);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_external_macro(cx, expr.span) {
+            return;
+        }
+        match expr.node {
+            ExprPath(ref qpath) => if let Some(def_id) = opt_def_id(resolve_node(cx, qpath, expr.hir_id)) {
+                self.check_method(cx, def_id, expr.span);
+            },
+            ExprMethodCall(..) => if let Some(def) = cx.tables.type_dependent_defs().get(expr.hir_id) {
+                self.check_method(cx, def.def_id(), expr.span);
+            },
+            ExprStruct(ref qpath, _, _) => if let Some(def_id) = opt_def_id(resolve_node(cx, qpath, expr.hir_id)) {
+                self.check_type(cx, def_id, expr.span);
+            },
+            _ => (),
+        }
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'a, 'tcx>, ty: &'tcx Ty) {
+        if in_external_macro(cx, ty.span) {
+            return;
+        }
+        if let TyPath(QPath::Resolved(_, ref path)) = ty.node {
+            if let Some(def_id) = opt_def_id(path.def) {
+                self.check_type(cx, def_id, ty.span);
+            }
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemUse(ref path, UseKind::Single) = item.node {
+            if let Some(def_id) = opt_def_id(path.def) {
+                self.check_method(cx, def_id, path.span);
+                self.check_type(cx, def_id, path.span);
+            }
+        }
+    }
+}
+
+/// Resolve a path with `path_to_def`, also looking in the inherent impls of types so methods like
+/// `chrono::Local::now` are found.
+fn resolve(cx: &LateContext, segments: &[&str]) -> Option<DefId> {
+    if let Some(def) = path_to_def(cx, segments) {
+        return opt_def_id(def);
+    }
+    let (name, ty_path) = segments.split_last()?;
+    let ty = opt_def_id(path_to_def(cx, ty_path)?)?;
+    cx.tcx
+        .inherent_impls(ty)
+        .iter()
+        .flat_map(|&imp| cx.tcx.associated_item_def_ids(imp).iter().cloned().collect::<Vec<_>>())
+        .find(|&id| cx.tcx.item_name(id) == *name)
+}
+
+fn report(cx: &LateContext, lint: &'static Lint, kind: &str, path: &DisallowedPath, span: Span) {
+    span_lint_and_then(
+        cx,
+        lint,
+        span,
+        &format!("use of a disallowed {} `{}`", kind, path.path()),
+        |db| if let Some(reason) = path.reason() {
+            db.note(reason);
+        },
+    );
+}
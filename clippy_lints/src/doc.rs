@@ -1,36 +1,131 @@
 use itertools::Itertools;
 use pulldown_cmark;
+use reachable_panic::visible_panic;
+use rustc::hir;
 use rustc::lint::*;
+use rustc_errors::{DiagnosticBuilder, Handler, Level};
+use rustc_errors::emitter::Emitter;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 use syntax::ast;
-use syntax::codemap::{BytePos, Span};
+use syntax::codemap::{BytePos, CodeMap, FilePathMapping, Span};
+use syntax::parse::{self, ParseSess};
+use syntax_pos::FileName;
 use syntax_pos::Pos;
-use utils::span_lint;
+use utils::{in_macro, item_header_span, match_type, paths, span_lint, span_lint_and_then};
 use url::Url;
 
-/// **What it does:** Checks for the presence of `_`, `::` or camel-case words
-/// outside ticks in documentation.
-///
-/// **Why is this bad?** *Rustdoc* supports markdown formatting, `_`, `::` and
-/// camel-case probably indicates some code which should be included between
-/// ticks. `_` can also be used for empasis in markdown, this lint tries to
-/// consider that.
-///
-/// **Known problems:** Lots of bad docs won’t be fixed, what the lint checks
-/// for is limited, and there are still false positives.
-///
-/// **Examples:**
-/// ```rust
-/// /// Do something with the foo_bar parameter. See also
-/// that::other::module::foo.
-/// // ^ `foo_bar` and `that::other::module::foo` should be ticked.
-/// fn doit(foo_bar) { .. }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for the presence of `_`, `::` or camel-case words
+    /// outside ticks in documentation.
+    ///
+    /// **Why is this bad?** *Rustdoc* supports markdown formatting, `_`, `::` and
+    /// camel-case probably indicates some code which should be included between
+    /// ticks. `_` can also be used for empasis in markdown, this lint tries to
+    /// consider that.
+    ///
+    /// **Known problems:** Lots of bad docs won’t be fixed, what the lint checks
+    /// for is limited, and there are still false positives.
+    ///
+    /// **Examples:**
+    /// ```rust
+    /// /// Do something with the foo_bar parameter. See also
+    /// that::other::module::foo.
+    /// // ^ `foo_bar` and `that::other::module::foo` should be ticked.
+    /// fn doit(foo_bar) { .. }
+    /// ```
     pub DOC_MARKDOWN,
     pedantic,
     "presence of `_`, `::` or camel-case outside backticks in documentation"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks the documentation of public functions returning a
+    /// `Result` for an `# Errors` section.
+    ///
+    /// **Why is this bad?** The callers need to know in which cases the function
+    /// fails, and how.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// /// Reads the configuration.
+    /// pub fn read_conf(path: &Path) -> io::Result<Conf> { .. }
+    /// ```
+    pub MISSING_ERRORS_DOC,
+    pedantic,
+    "public function returning `Result` without an `# Errors` section in its documentation"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks the documentation of public functions calling
+    /// `panic!`, `unwrap` or `expect` for a `# Panics` section.
+    ///
+    /// **Why is this bad?** The callers need to know in which cases the function
+    /// panics to avoid them.
+    ///
+    /// **Known problems:** Only the panics in the function itself are found, see
+    /// `undocumented_reachable_panic` to follow its calls.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// /// Returns the port of the server.
+    /// pub fn port(&self) -> u16 {
+    ///     self.port.expect("the port is set in `new`")
+    /// }
+    /// ```
+    pub MISSING_PANICS_DOC,
+    pedantic,
+    "public function which may panic without a `# Panics` section in its documentation"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks the documentation of public unsafe functions and
+    /// unsafe traits for a `# Safety` section.
+    ///
+    /// **Why is this bad?** The callers of an unsafe function, and the
+    /// implementations of an unsafe trait, need to know the invariants they
+    /// must uphold.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// /// Returns the element at `index`.
+    /// pub unsafe fn get_unchecked(&self, index: usize) -> &T { .. }
+    /// ```
+    pub MISSING_SAFETY_DOC,
+    pedantic,
+    "public unsafe function or trait without a `# Safety` section in its documentation"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks the Rust code blocks of the documentation for
+    /// syntax errors, fences which are never closed, and `ignore` blocks without
+    /// an explanation like `ignore (needs a database)`.
+    ///
+    /// **Why is this bad?** Code blocks which do not parse fail as doc tests, or
+    /// mislead the readers when they are ignored. An unclosed fence turns the rest
+    /// of the documentation into code. Ignoring a block without saying why hides
+    /// whether it is still correct.
+    ///
+    /// **Known problems:** The code is only parsed, not compiled. Blocks without
+    /// `fn main` are parsed as the body of a function, as `rustdoc` does.
+    ///
+    /// **Example:**
+    /// ````rust
+    /// /// ```ignore
+    /// /// let x = ;
+    /// /// ```
+    /// fn foo() {}
+    /// ````
+    pub INVALID_DOC_CODE_BLOCK,
+    pedantic,
+    "code block of the documentation with a syntax error, an unclosed fence or an unexplained `ignore`"
+}
+
 #[derive(Clone)]
 pub struct Doc {
     valid_idents: Vec<String>,
@@ -46,7 +141,7 @@ impl Doc {
 
 impl LintPass for Doc {
     fn get_lints(&self) -> LintArray {
-        lint_array![DOC_MARKDOWN]
+        lint_array![DOC_MARKDOWN, INVALID_DOC_CODE_BLOCK]
     }
 }
 
@@ -60,6 +155,94 @@ impl EarlyLintPass for Doc {
     }
 }
 
+/// Checks the sections of the documentation of public items, this needs the types and the
+/// visibility of the items.
+#[derive(Copy, Clone)]
+pub struct DocSections;
+
+impl LintPass for DocSections {
+    fn get_lints(&self) -> LintArray {
+        lint_array![MISSING_ERRORS_DOC, MISSING_PANICS_DOC, MISSING_SAFETY_DOC]
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DocSections {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::Item) {
+        if in_macro(item.span) || !cx.access_levels.is_exported(item.id) {
+            return;
+        }
+        match item.node {
+            hir::ItemFn(_, unsafety, _, _, _, body_id) => {
+                check_fn_sections(cx, item.id, item.span, &item.attrs, unsafety, body_id);
+            },
+            hir::ItemTrait(_, hir::Unsafety::Unsafe, ..) => if !has_section(&item.attrs, "Safety") {
+                span_lint(
+                    cx,
+                    MISSING_SAFETY_DOC,
+                    item_header_span(cx, item.span),
+                    "docs for unsafe trait missing `# Safety` section",
+                );
+            },
+            _ => (),
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::ImplItem) {
+        if in_macro(item.span) || !cx.access_levels.is_exported(item.id) {
+            return;
+        }
+        // the methods of trait impls are documented by the trait
+        let parent = cx.tcx.hir.get_parent(item.id);
+        if let Some(hir::map::NodeItem(&hir::Item { node: hir::ItemImpl(_, _, _, _, Some(_), _, _), .. })) =
+            cx.tcx.hir.find(parent)
+        {
+            return;
+        }
+        if let hir::ImplItemKind::Method(ref sig, body_id) = item.node {
+            check_fn_sections(cx, item.id, item.span, &item.attrs, sig.unsafety, body_id);
+        }
+    }
+}
+
+fn check_fn_sections<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    id: ast::NodeId,
+    span: Span,
+    attrs: &[ast::Attribute],
+    unsafety: hir::Unsafety,
+    body_id: hir::BodyId,
+) {
+    let span = item_header_span(cx, span);
+    if unsafety == hir::Unsafety::Unsafe && !has_section(attrs, "Safety") {
+        span_lint(cx, MISSING_SAFETY_DOC, span, "unsafe function's docs miss `# Safety` section");
+    }
+
+    let def_id = cx.tcx.hir.local_def_id(id);
+    let ret_ty = *cx.tcx.fn_sig(def_id).output().skip_binder();
+    if match_type(cx, ret_ty, &paths::RESULT) && !has_section(attrs, "Errors") {
+        span_lint(
+            cx,
+            MISSING_ERRORS_DOC,
+            span,
+            "docs for function returning `Result` missing `# Errors` section",
+        );
+    }
+
+    if !has_section(attrs, "Panics") {
+        if let Some((panic, what)) = visible_panic(cx, cx.tcx.hir.body(body_id)) {
+            span_lint_and_then(
+                cx,
+                MISSING_PANICS_DOC,
+                span,
+                "docs for function which may panic missing `# Panics` section",
+                |db| {
+                    db.span_note(panic, &format!("first possible panic found here, with {}", what));
+                },
+            );
+        }
+    }
+}
+
 struct Parser<'a> {
     parser: pulldown_cmark::Parser<'a>,
 }
@@ -138,6 +321,43 @@ pub fn strip_doc_comment_decoration(comment: &str, span: Span) -> (String, Vec<(
     panic!("not a doc-comment: {}", comment);
 }
 
+/// The documentation in `attrs`, without the comment decoration.
+fn doc_text(attrs: &[ast::Attribute]) -> String {
+    let mut doc = String::new();
+    for attr in attrs {
+        if attr.is_sugared_doc {
+            if let Some(current) = attr.value_str() {
+                doc.push_str(&strip_doc_comment_decoration(&current.as_str(), attr.span).0);
+            }
+        } else if attr.check_name("doc") {
+            if let Some(current) = attr.value_str() {
+                doc.push_str(&current.as_str());
+                doc.push('\n');
+            }
+        }
+    }
+    doc
+}
+
+/// Returns true if the documentation in `attrs` has a section with the heading `name`, like
+/// `# Panics`.
+pub fn has_section(attrs: &[ast::Attribute], name: &str) -> bool {
+    use pulldown_cmark::Event::*;
+    use pulldown_cmark::Tag::*;
+
+    let doc = doc_text(attrs);
+    let mut in_header = false;
+    for event in pulldown_cmark::Parser::new(&doc) {
+        match event {
+            Start(Header(_)) => in_header = true,
+            End(Header(_)) => in_header = false,
+            Text(ref text) if in_header && text.trim() == name => return true,
+            _ => (),
+        }
+    }
+    false
+}
+
 pub fn check_attrs<'a>(cx: &EarlyContext, valid_idents: &[String], attrs: &'a [ast::Attribute]) {
     let mut doc = String::new();
     let mut spans = vec![];
@@ -166,6 +386,10 @@ pub fn check_attrs<'a>(cx: &EarlyContext, valid_idents: &[String], attrs: &'a [a
     }
 
     if !doc.is_empty() {
+        if let Some(offset) = unclosed_fence(&doc) {
+            span_lint(cx, INVALID_DOC_CODE_BLOCK, span_at(&spans, offset), "unclosed code block fence");
+        }
+
         let parser = Parser::new(pulldown_cmark::Parser::new(&doc));
         let parser = parser.coalesce(|x, y| {
             use pulldown_cmark::Event::*;
@@ -182,13 +406,14 @@ pub fn check_attrs<'a>(cx: &EarlyContext, valid_idents: &[String], attrs: &'a [a
                 (x, y) => Err(((x_offset, x), (y_offset, y))),
             }
         });
-        check_doc(cx, valid_idents, parser, &spans);
+        check_doc(cx, valid_idents, &doc, parser, &spans);
     }
 }
 
 fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
     cx: &EarlyContext,
     valid_idents: &[String],
+    doc: &str,
     docs: Events,
     spans: &[(usize, Span)],
 ) {
@@ -197,17 +422,37 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
 
     let mut in_code = false;
     let mut in_link = None;
+    let mut code_block = None;
 
     for (offset, event) in docs {
         match event {
-            Start(CodeBlock(_)) | Start(Code) => in_code = true,
-            End(CodeBlock(_)) | End(Code) => in_code = false,
+            Start(CodeBlock(info)) => {
+                in_code = true;
+                code_block = Some(DocCodeBlock {
+                    info: info.into_owned(),
+                    start: offset,
+                    code: String::new(),
+                });
+            },
+            End(CodeBlock(_)) => {
+                in_code = false;
+                if let Some(block) = code_block.take() {
+                    check_code_block(cx, doc, &block, spans);
+                }
+            },
+            Start(Code) => in_code = true,
+            End(Code) => in_code = false,
             Start(Link(link, _)) => in_link = Some(link),
             End(Link(_, _)) => in_link = None,
             Start(_tag) | End(_tag) => (),         // We don't care about other tags
             Html(_html) | InlineHtml(_html) => (), // HTML is weird, just ignore it
             SoftBreak | HardBreak => (),
             FootnoteReference(text) | Text(text) => {
+                if let Some(ref mut block) = code_block {
+                    block.code.push_str(&text);
+                    continue;
+                }
+
                 if Some(&text) == in_link.as_ref() {
                     // Probably a link of the form `<http://example.com>`
                     // Which are represented as a link to "http://example.com" with
@@ -216,21 +461,188 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
                 }
 
                 if !in_code {
-                    let index = match spans.binary_search_by(|c| c.0.cmp(&offset)) {
-                        Ok(o) => o,
-                        Err(e) => e - 1,
-                    };
+                    check_text(cx, valid_idents, &text, span_at(spans, offset));
+                }
+            },
+        }
+    }
+}
 
-                    let (begin, span) = spans[index];
+/// The span of the documentation from `offset` to the end of its line.
+fn span_at(spans: &[(usize, Span)], offset: usize) -> Span {
+    let index = match spans.binary_search_by(|c| c.0.cmp(&offset)) {
+        Ok(o) => o,
+        Err(e) => e - 1,
+    };
 
-                    // Adjust for the beginning of the current `Event`
-                    let span = span.with_lo(span.lo() + BytePos::from_usize(offset - begin));
+    let (begin, span) = spans[index];
 
-                    check_text(cx, valid_idents, &text, span);
-                }
+    // Adjust for the beginning of the current `Event`
+    span.with_lo(span.lo() + BytePos::from_usize(offset - begin))
+}
+
+/// A code block of the documentation.
+struct DocCodeBlock {
+    /// The info string of the fence, eg. `rust,ignore`.
+    info: String,
+    /// The offset of the fence in the documentation.
+    start: usize,
+    code: String,
+}
+
+/// The attributes `rustdoc` accepts on Rust code blocks.
+const RUST_ATTRIBUTES: &[&str] = &[
+    "rust",
+    "ignore",
+    "should_panic",
+    "no_run",
+    "compile_fail",
+    "test_harness",
+    "allow_fail",
+];
+
+fn check_code_block(cx: &EarlyContext, doc: &str, block: &DocCodeBlock, spans: &[(usize, Span)]) {
+    let (attrs, explanation) = split_info(&block.info);
+    let has = |name: &str| attrs.iter().any(|attr| *attr == name);
+
+    if has("ignore") && explanation.is_none() {
+        span_lint_and_then(
+            cx,
+            INVALID_DOC_CODE_BLOCK,
+            span_at(spans, line_offset(doc, block.start, 0)),
+            "code block ignored without an explanation",
+            |db| {
+                db.help("say why the block is ignored, like `ignore (needs a database)`");
             },
+        );
+    }
+
+    let is_rust = attrs.iter().all(|attr| RUST_ATTRIBUTES.contains(attr));
+    if !is_rust || has("ignore") || has("compile_fail") {
+        return;
+    }
+    if let Some((message, line)) = syntax_error(&block.code) {
+        // the code starts on the line after the fence
+        let line = line.min(block.code.lines().count().saturating_sub(1));
+        span_lint(
+            cx,
+            INVALID_DOC_CODE_BLOCK,
+            span_at(spans, line_offset(doc, block.start, line + 1)),
+            &format!("syntax error in the code block: {}", message),
+        );
+    }
+}
+
+/// The offset of the first non-whitespace character of the `n`th line of `doc` from the one
+/// `offset` is on.
+fn line_offset(doc: &str, mut offset: usize, n: usize) -> usize {
+    for _ in 0..n {
+        match doc[offset..].find('\n') {
+            Some(end) => offset += end + 1,
+            None => break,
+        }
+    }
+    let rest = &doc[offset..];
+    offset + rest.len() - rest.trim_left().len()
+}
+
+/// Splits the info string of a fence into its attributes and the explanation between
+/// parentheses, as in `ignore (needs a database)`.
+fn split_info(info: &str) -> (Vec<&str>, Option<&str>) {
+    let (attrs, explanation) = match info.find('(') {
+        Some(open) => {
+            let explanation = info[open + 1..].trim().trim_right_matches(')').trim();
+            (&info[..open], if explanation.is_empty() { None } else { Some(explanation) })
+        },
+        None => (info, None),
+    };
+    let attrs = attrs
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|attr| !attr.is_empty())
+        .collect();
+    (attrs, explanation)
+}
+
+/// The offset of the first fence of `doc` which is never closed.
+fn unclosed_fence(doc: &str) -> Option<usize> {
+    // the offset, the character and the length of the open fence
+    let mut open: Option<(usize, char, usize)> = None;
+    let mut offset = 0;
+    for line in doc.split('\n') {
+        let trimmed = line.trim_left();
+        let c = trimmed.chars().next().unwrap_or(' ');
+        let len = trimmed.chars().take_while(|&x| x == c).count();
+        if (c == '`' || c == '~') && len >= 3 {
+            match open {
+                Some((_, open_c, open_len)) => if c == open_c && len >= open_len && trimmed[len..].trim().is_empty() {
+                    open = None;
+                },
+                None => open = Some((offset + line.len() - trimmed.len(), c, len)),
+            }
         }
+        offset += line.len() + 1;
     }
+    open.map(|(offset, _, _)| offset)
+}
+
+/// Collects the errors of the parser instead of printing them.
+struct ErrorCollector(Arc<Mutex<Vec<(String, Option<Span>)>>>);
+
+impl Emitter for ErrorCollector {
+    fn emit(&mut self, db: &DiagnosticBuilder) {
+        let is_error = match db.level {
+            Level::Bug | Level::Fatal | Level::PhaseFatal | Level::Error => true,
+            _ => false,
+        };
+        if is_error {
+            if let Ok(mut errors) = self.0.lock() {
+                errors.push((db.message(), db.span.primary_span()));
+            }
+        }
+    }
+}
+
+/// The first syntax error of a code block, and the line of the block it is on. As `rustdoc`
+/// does, the lines starting with `#` are kept and the code is wrapped in a `main` function if
+/// it has none.
+fn syntax_error(code: &str) -> Option<(String, usize)> {
+    let code = code
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_left();
+            if trimmed.starts_with("# ") {
+                &trimmed[2..]
+            } else if trimmed == "#" {
+                ""
+            } else {
+                line
+            }
+        })
+        .join("\n");
+    let wrapped = !code.contains("fn main");
+    let source = if wrapped {
+        format!("fn main() {{\n{}\n}}", code)
+    } else {
+        code
+    };
+
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let codemap = Rc::new(CodeMap::new(FilePathMapping::empty()));
+    let handler = Handler::with_emitter(false, false, Box::new(ErrorCollector(Arc::clone(&errors))));
+    let sess = ParseSess::with_span_handler(handler, Rc::clone(&codemap));
+    let name = FileName::Custom("doc code block".to_owned());
+    // the parser panics on fatal errors, after emitting them
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        if let Err(mut db) = parse::parse_crate_from_source_str(name, source, &sess) {
+            db.emit();
+        }
+    }));
+
+    let errors = errors.lock().ok()?;
+    let &(ref message, span) = errors.first()?;
+    let line = span.map_or(0, |span| codemap.lookup_char_pos(span.lo()).line - 1);
+    let line = if wrapped { line.saturating_sub(1) } else { line };
+    Some((message.clone(), line))
 }
 
 fn check_text(cx: &EarlyContext, valid_idents: &[String], text: &str, span: Span) {
@@ -1,20 +1,21 @@
 use syntax::ast::*;
-use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use utils::span_lint;
 
-/// **What it does:** Checks for unnecessary double parentheses.
-///
-/// **Why is this bad?** This makes code harder to read and might indicate a
-/// mistake.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// ((0))
-/// foo((0))
-/// ((1, 2))
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for unnecessary double parentheses.
+    ///
+    /// **Why is this bad?** This makes code harder to read and might indicate a
+    /// mistake.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// ((0))
+    /// foo((0))
+    /// ((1, 2))
+    /// ```
     pub DOUBLE_PARENS,
     complexity,
     "Warn on unnecessary double parentheses"
@@ -34,20 +35,20 @@ impl EarlyLintPass for DoubleParens {
         match expr.node {
             ExprKind::Paren(ref in_paren) => match in_paren.node {
                 ExprKind::Paren(_) | ExprKind::Tup(_) => {
-                    cx.span_lint(DOUBLE_PARENS, expr.span, "Consider removing unnecessary double parentheses");
+                    span_lint(cx, DOUBLE_PARENS, expr.span, "Consider removing unnecessary double parentheses");
                 },
                 _ => {},
             },
             ExprKind::Call(_, ref params) => if params.len() == 1 {
                 let param = &params[0];
                 if let ExprKind::Paren(_) = param.node {
-                    cx.span_lint(DOUBLE_PARENS, param.span, "Consider removing unnecessary double parentheses");
+                    span_lint(cx, DOUBLE_PARENS, param.span, "Consider removing unnecessary double parentheses");
                 }
             },
             ExprKind::MethodCall(_, ref params) => if params.len() == 2 {
                 let param = &params[1];
                 if let ExprKind::Paren(_) = param.node {
-                    cx.span_lint(DOUBLE_PARENS, param.span, "Consider removing unnecessary double parentheses");
+                    span_lint(cx, DOUBLE_PARENS, param.span, "Consider removing unnecessary double parentheses");
                 }
             },
             _ => {},
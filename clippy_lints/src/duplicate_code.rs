@@ -0,0 +1,146 @@
+use rustc::hir::*;
+use rustc::hir::map::Node;
+use rustc::lint::*;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+use utils::conf::Threshold;
+use utils::{in_macro, span_lint_and_then, SpanlessEq, SpanlessHash};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for blocks, including the bodies of functions,
+    /// with at least `duplicate-code-threshold` statements which are repeated
+    /// elsewhere in the crate, up to the names of the local variables and the
+    /// values of the literals.
+    ///
+    /// **Why is this bad?** Copied code must be fixed in every copy, and the copies
+    /// drift apart over time.
+    ///
+    /// **Known problems:** The lint is only reported at the first copy, so it can
+    /// only be allowed for the whole crate. The patterns of the `let` statements
+    /// are not compared.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn mean(values: &[f64]) -> f64 {
+    ///     let mut sum = 0.0;
+    ///     let mut count = 0;
+    ///     for &v in values {
+    ///         sum += v;
+    ///         count += 1;
+    ///     }
+    ///     let count = count.max(1);
+    ///     sum / f64::from(count)
+    /// }
+    ///
+    /// // in another module
+    /// fn average(xs: &[f64]) -> f64 {
+    ///     let mut total = 0.0;
+    ///     let mut n = 0;
+    ///     for &x in xs {
+    ///         total += x;
+    ///         n += 1;
+    ///     }
+    ///     let n = n.max(1);
+    ///     total / f64::from(n)
+    /// }
+    /// ```
+    pub DUPLICATE_CODE,
+    pedantic,
+    "blocks repeated elsewhere in the crate, up to the names of the variables and the literals"
+}
+
+pub struct DuplicateCode {
+    threshold: Threshold,
+    /// The blocks large enough to be compared, by hash.
+    blocks: HashMap<u64, Vec<NodeId>>,
+}
+
+impl DuplicateCode {
+    pub fn new(threshold: Threshold) -> Self {
+        Self {
+            threshold,
+            blocks: HashMap::new(),
+        }
+    }
+}
+
+impl LintPass for DuplicateCode {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DUPLICATE_CODE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DuplicateCode {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        if in_macro(block.span) {
+            return;
+        }
+        let len = block.stmts.len() + if block.expr.is_some() { 1 } else { 0 };
+        if (len as u64) < self.threshold.at(cx.sess().codemap(), block.span) {
+            return;
+        }
+
+        let mut h = SpanlessHash::new(cx).ignore_locals_and_literals();
+        h.hash_block(block);
+        self.blocks.entry(h.finish()).or_insert_with(Vec::new).push(block.id);
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        let mut copies: Vec<Vec<Span>> = Vec::new();
+        for ids in self.blocks.values().filter(|ids| ids.len() > 1) {
+            // different blocks can have the same hash, each block joins the first group it is equal to
+            let mut groups: Vec<Vec<&Block>> = Vec::new();
+            for &id in ids {
+                let block = match cx.tcx.hir.get(id) {
+                    Node::NodeBlock(block) => block,
+                    _ => continue,
+                };
+                let group = groups
+                    .iter_mut()
+                    .find(|group| SpanlessEq::new(cx).ignore_locals_and_literals().eq_block(group[0], block));
+                match group {
+                    Some(group) => group.push(block),
+                    None => groups.push(vec![block]),
+                }
+            }
+            copies.extend(groups.into_iter().filter(|group| group.len() > 1).map(|group| {
+                let mut spans = group.iter().map(|block| block.span).collect::<Vec<_>>();
+                spans.sort_by_key(|span| span.lo());
+                spans
+            }));
+        }
+
+        // the largest blocks first, the blocks inside their copies are not reported again
+        copies.sort_by_key(|spans| (Reverse(spans[0].hi() - spans[0].lo()), spans[0].lo()));
+        let mut reported: Vec<&[Span]> = Vec::new();
+        for spans in &copies {
+            let inside_reported = spans.iter().all(|span| {
+                reported
+                    .iter()
+                    .flat_map(|spans| spans.iter())
+                    .any(|outer| outer.lo() <= span.lo() && span.hi() <= outer.hi())
+            });
+            if !inside_reported {
+                reported.push(&spans[..]);
+            }
+        }
+
+        reported.sort_by_key(|spans| spans[0].lo());
+        for spans in reported {
+            span_lint_and_then(
+                cx,
+                DUPLICATE_CODE,
+                spans[0],
+                &format!("this block is repeated {} times in the crate", spans.len()),
+                |db| {
+                    for &span in &spans[1..] {
+                        db.span_note(span, "a copy is here");
+                    }
+                    db.help("move the code to a function, with the variables and literals which differ as arguments");
+                },
+            );
+        }
+    }
+}
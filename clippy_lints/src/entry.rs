@@ -1,33 +1,50 @@
 use rustc::hir::*;
-use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::intravisit::{walk_expr, walk_stmt, NestedVisitorMap, Visitor};
 use rustc::lint::*;
+use rustc::ty;
 use syntax::codemap::Span;
 use utils::SpanlessEq;
-use utils::{get_item_name, match_type, paths, snippet, span_lint_and_then, walk_ptrs_ty};
-
-/// **What it does:** Checks for uses of `contains_key` + `insert` on `HashMap`
-/// or `BTreeMap`.
-///
-/// **Why is this bad?** Using `entry` is more efficient.
-///
-/// **Known problems:** Some false negatives, eg.:
-/// ```rust
-/// let k = &key;
-/// if !m.contains_key(k) { m.insert(k.clone(), v); }
-/// ```
-///
-/// **Example:**
-/// ```rust
-/// if !m.contains_key(&k) { m.insert(k, v) }
-/// ```
-/// can be rewritten as:
-/// ```rust
-/// m.entry(k).or_insert(v);
-/// ```
+use utils::{in_macro, is_refutable, match_qpath, match_type, paths, snippet, snippet_opt, span_lint_and_then,
+            walk_ptrs_ty};
+use utils::sugg::Sugg;
+use utils::{Applicability, DiagnosticBuilderApplicabilityExt};
+
 declare_clippy_lint! {
+    /// **What it does:** Checks for uses of `contains_key` or `get` followed by
+    /// `insert` and of `contains_key` followed by `remove` on `HashMap` or
+    /// `BTreeMap`, and for uses of `contains` followed by `insert` or `remove` on
+    /// `HashSet` or `BTreeSet`.
+    ///
+    /// **Why is this bad?** Using `entry`, or the result of `insert` and `remove`,
+    /// is more efficient: the key is only looked up once.
+    ///
+    /// **Known problems:** Some false negatives, eg.:
+    /// ```rust
+    /// let k = &key;
+    /// if !m.contains_key(k) { m.insert(k.clone(), v); }
+    /// ```
+    ///
+    /// With the suggestions using `Entry`, the code checking the key may not borrow
+    /// the map anymore.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// if !m.contains_key(&k) { m.insert(k, v) }
+    /// if let Some(count) = m.get_mut(&k) { *count += 1; } else { m.insert(k, 1); }
+    /// if !s.contains(&k) { s.insert(k); println!("new key"); }
+    /// ```
+    /// can be rewritten as:
+    /// ```rust
+    /// m.entry(k).or_insert(v);
+    /// match m.entry(k) {
+    ///     Entry::Occupied(entry) => { let count = entry.into_mut(); *count += 1; },
+    ///     Entry::Vacant(entry) => { entry.insert(1); },
+    /// }
+    /// if s.insert(k) { println!("new key"); }
+    /// ```
     pub MAP_ENTRY,
     perf,
-    "use of `contains_key` followed by `insert` on a `HashMap` or `BTreeMap`"
+    "checking for a key in a map or a set before inserting or removing it"
 }
 
 #[derive(Copy, Clone)]
@@ -39,122 +56,406 @@ impl LintPass for HashMapLint {
     }
 }
 
+/// The collections checked, their name, and whether they are sets.
+const COLLECTIONS: [(&[&str], &str, bool); 4] = [
+    (&paths::HASHMAP, "HashMap", false),
+    (&paths::BTREEMAP, "BTreeMap", false),
+    (&paths::HASHSET, "HashSet", true),
+    (&paths::BTREESET, "BTreeSet", true),
+];
+
+/// A call looking a key up in a map or a set, eg. `m.contains_key(&k)`.
+struct Check<'b> {
+    /// The method called, `contains_key`, `contains`, `get` or `get_mut`.
+    method: &'static str,
+    /// The map or the set.
+    collection: &'b Expr,
+    /// The key, without its `&`.
+    key: &'b Expr,
+    /// The name of the type of the collection, eg. `HashMap`.
+    ty: &'static str,
+    set: bool,
+}
+
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for HashMapLint {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
-        if let ExprIf(ref check, ref then_block, ref else_block) = expr.node {
-            if let ExprUnary(UnOp::UnNot, ref check) = check.node {
-                if let Some((ty, map, key)) = check_cond(cx, check) {
-                    // in case of `if !m.contains_key(&k) { m.insert(k, v); }`
-                    // we can give a better error message
-                    let sole_expr = {
-                        else_block.is_none() && if let ExprBlock(ref then_block) = then_block.node {
-                            (then_block.expr.is_some() as usize) + then_block.stmts.len() == 1
-                        } else {
-                            true
-                        }
-                    };
-
-                    let mut visitor = InsertVisitor {
-                        cx,
-                        span: expr.span,
-                        ty,
-                        map,
-                        key,
-                        sole_expr,
+        if in_macro(expr.span) {
+            return;
+        }
+        match expr.node {
+            ExprIf(ref cond, ref then, ref els) => {
+                let (negated, cond) = match cond.node {
+                    ExprUnary(UnOp::UnNot, ref cond) => (true, &**cond),
+                    _ => (false, &**cond),
+                };
+                if let Some(check) = check_call(cx, cond, &["contains_key", "contains"]) {
+                    let els = els.as_ref().map(|e| &**e);
+                    // the branches where the key is absent and present
+                    let (absent, present) = if negated {
+                        (Some(&**then), els)
+                    } else {
+                        (els, Some(&**then))
                     };
-
-                    walk_expr(&mut visitor, &**then_block);
+                    check_contains(cx, expr, &check, absent, present);
                 }
-            } else if let Some(ref else_block) = *else_block {
-                if let Some((ty, map, key)) = check_cond(cx, check) {
-                    let mut visitor = InsertVisitor {
-                        cx,
-                        span: expr.span,
-                        ty,
-                        map,
-                        key,
-                        sole_expr: false,
-                    };
-
-                    walk_expr(&mut visitor, else_block);
+            },
+            ExprMatch(ref scrutinee, ref arms, MatchSource::Normal) |
+            ExprMatch(ref scrutinee, ref arms, MatchSource::IfLetDesugar { .. }) => {
+                if let Some(check) = check_call(cx, scrutinee, &["get", "get_mut"]) {
+                    check_get(cx, expr, &check, arms);
                 }
-            }
+            },
+            _ => (),
         }
     }
 }
 
-fn check_cond<'a, 'tcx, 'b>(
+/// Recognize `collection.method(&key)` on the collections we know, for one of `methods`.
+fn check_call<'a, 'tcx, 'b>(
     cx: &'a LateContext<'a, 'tcx>,
-    check: &'b Expr,
-) -> Option<(&'static str, &'b Expr, &'b Expr)> {
+    expr: &'b Expr,
+    methods: &[&'static str],
+) -> Option<Check<'b>> {
     if_chain! {
-        if let ExprMethodCall(ref path, _, ref params) = check.node;
-        if params.len() >= 2;
-        if path.name == "contains_key";
+        if let ExprMethodCall(ref path, _, ref params) = expr.node;
+        if params.len() == 2;
+        if let Some(&method) = methods.iter().find(|&&method| path.name == method);
         if let ExprAddrOf(_, ref key) = params[1].node;
         then {
-            let map = &params[0];
-            let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(map));
-
-            return if match_type(cx, obj_ty, &paths::BTREEMAP) {
-                Some(("BTreeMap", map, key))
+            let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&params[0]));
+            let &(_, ty, set) = COLLECTIONS.iter().find(|&&(path, _, _)| match_type(cx, obj_ty, path))?;
+            // `contains` is the method of the sets, the others the methods of the maps
+            if set != (method == "contains") {
+                return None;
             }
-            else if match_type(cx, obj_ty, &paths::HASHMAP) {
-                Some(("HashMap", map, key))
+            return Some(Check {
+                method,
+                collection: &params[0],
+                key,
+                ty,
+                set,
+            });
+        }
+    }
+
+    None
+}
+
+/// `if !m.contains_key(&k) { .. }` and `if s.contains(&k) { .. }`, with the branches where the key
+/// is absent and present.
+fn check_contains(cx: &LateContext, expr: &Expr, check: &Check, absent: Option<&Expr>, present: Option<&Expr>) {
+    if !check.set {
+        if let Some(absent) = absent {
+            let inserts = find_inserts(cx, check, absent);
+            if !inserts.is_empty() {
+                let sole = present.is_none() && inserts.len() == 1 && is_sole_statement(absent, inserts[0].span);
+                let (sugg, applicability) = if sole {
+                    let insert = &inserts[0];
+                    let entry = entry_call(cx, check, insert.key);
+                    let value = Sugg::hir(cx, insert.value, "..");
+                    // the value was only evaluated if the key was absent
+                    let sugg = match insert.value.node {
+                        ExprLit(_) | ExprPath(_) => format!("{}.or_insert({})", entry, value),
+                        _ => format!("{}.or_insert_with(|| {})", entry, value),
+                    };
+                    (statement(cx, expr, sugg), Applicability::MachineApplicable)
+                } else {
+                    let absent = match (replace_inserts(cx, absent, &inserts), &absent.node) {
+                        (Some(absent), &ExprBlock(_)) => absent,
+                        // `else if`
+                        (Some(absent), _) => format!("{{ {} }}", absent),
+                        (None, _) => return,
+                    };
+                    let mut sugg = format!(
+                        "if let {}::Vacant(entry) = {} {}",
+                        entry_type(check),
+                        entry_call(cx, check, inserts[0].key),
+                        absent
+                    );
+                    if let Some(present) = present {
+                        sugg.push_str(&format!(" else {}", snippet(cx, present.span, "{ .. }")));
+                    }
+                    (sugg, Applicability::MaybeIncorrect)
+                };
+                report(cx, expr, check, "insert", sugg, applicability);
+                return;
             }
-            else {
-                None
-            };
         }
+    } else if let Some((block, stmt, update)) = absent.and_then(|absent| first_update(cx, check, absent, "insert")) {
+        let call = format!(
+            "{}.insert({})",
+            Sugg::hir(cx, check.collection, "set").maybe_par(),
+            snippet(cx, update.span, "..")
+        );
+        let sugg = update_then(cx, expr, &call, "", block, stmt, present);
+        report(cx, expr, check, "insert", sugg, Applicability::MachineApplicable);
+        return;
+    }
+
+    if let Some((block, stmt, update)) = present.and_then(|present| first_update(cx, check, present, "remove")) {
+        let call = format!(
+            "{}.remove({})",
+            Sugg::hir(cx, check.collection, "map").maybe_par(),
+            snippet(cx, update.span, "..")
+        );
+        // `remove` returns whether the key was present for the sets, and its value for the maps
+        let test = if check.set { "" } else { ".is_some()" };
+        let sugg = update_then(cx, expr, &call, test, block, stmt, absent);
+        report(cx, expr, check, "remove", sugg, Applicability::MachineApplicable);
+    }
+}
+
+/// `if let Some(v) = m.get(&k) { .. } else { .. }` and `match m.get_mut(&k) { Some(v) => .., None => .. }`.
+fn check_get(cx: &LateContext, expr: &Expr, check: &Check, arms: &[Arm]) {
+    if arms.len() != 2 || arms.iter().any(|arm| arm.pats.len() != 1 || arm.guard.is_some()) {
+        return;
+    }
+    // the arm where the key is present and its binding, and the arm where it is absent
+    let (present, binding, absent) = match (some_binding(&arms[0]), some_binding(&arms[1])) {
+        (Some(binding), None) if is_none(&arms[1]) => (&arms[0].body, binding, &arms[1].body),
+        (None, Some(binding)) if is_none(&arms[0]) => (&arms[1].body, binding, &arms[0].body),
+        _ => return,
+    };
+    if is_refutable(cx, binding) {
+        return;
+    }
+
+    let inserts = find_inserts(cx, check, absent);
+    if inserts.is_empty() {
+        return;
+    }
+    let absent = match replace_inserts(cx, absent, &inserts) {
+        Some(absent) => absent,
+        None => return,
+    };
+    let present = snippet(cx, present.span, "..");
+    let (occupied, present) = if let PatKind::Wild = binding.node {
+        ("_", present.into_owned())
+    } else {
+        let getter = if check.method == "get_mut" { "into_mut" } else { "get" };
+        let binding = format!("let {} = entry.{}();", snippet(cx, binding.span, ".."), getter);
+        let present = if present.starts_with('{') {
+            format!("{{ {}{}", binding, &present[1..])
+        } else {
+            format!("{{ {} {} }}", binding, present)
+        };
+        ("entry", present)
+    };
+    let sugg = format!(
+        "match {} {{ {entry}::Occupied({}) => {}, {entry}::Vacant(entry) => {} }}",
+        entry_call(cx, check, inserts[0].key),
+        occupied,
+        present,
+        absent,
+        entry = entry_type(check)
+    );
+    report(cx, expr, check, "insert", sugg, Applicability::MaybeIncorrect);
+}
+
+/// The pattern in `Some(..)` if the arm matches `Some`.
+fn some_binding(arm: &Arm) -> Option<&Pat> {
+    match arm.pats[0].node {
+        PatKind::TupleStruct(ref path, ref inner, _) if inner.len() == 1 && match_qpath(path, &paths::OPTION_SOME) => {
+            Some(&inner[0])
+        },
+        _ => None,
+    }
+}
+
+/// Returns true if the arm matches `None` or anything.
+fn is_none(arm: &Arm) -> bool {
+    match arm.pats[0].node {
+        PatKind::Wild => true,
+        PatKind::Path(ref path) => match_qpath(path, &paths::OPTION_NONE),
+        _ => false,
+    }
+}
+
+fn report(cx: &LateContext, expr: &Expr, check: &Check, update: &str, sugg: String, applicability: Applicability) {
+    span_lint_and_then(
+        cx,
+        MAP_ENTRY,
+        expr.span,
+        &format!("usage of `{}` followed by `{}` on a `{}`", check.method, update, check.ty),
+        |db| {
+            db.span_suggestion_with_applicability(expr.span, "consider using", sugg, applicability);
+        },
+    );
+}
+
+/// `m.entry(key)`.
+fn entry_call(cx: &LateContext, check: &Check, key: &Expr) -> String {
+    format!(
+        "{}.entry({})",
+        Sugg::hir(cx, check.collection, "map").maybe_par(),
+        Sugg::hir(cx, key, "..")
+    )
+}
+
+/// The full path of the `Entry` type of the map, which is usually not imported.
+fn entry_type(check: &Check) -> &'static str {
+    if check.ty == "BTreeMap" {
+        "std::collections::btree_map::Entry"
+    } else {
+        "std::collections::hash_map::Entry"
+    }
+}
+
+/// The suggestion as a statement if `expr` has type `()`, the calls suggested return a value.
+fn statement(cx: &LateContext, expr: &Expr, sugg: String) -> String {
+    match cx.tables.expr_ty(expr).sty {
+        ty::TyTuple(tys) if tys.is_empty() => sugg + ";",
+        _ => sugg,
+    }
+}
+
+/// A call to `insert` on the map checked, with the key checked.
+struct Insert<'b> {
+    span: Span,
+    key: &'b Expr,
+    value: &'b Expr,
+    /// Whether the `Option` returned by `insert` is used.
+    used: bool,
+}
+
+fn find_inserts<'b>(cx: &LateContext, check: &Check, branch: &'b Expr) -> Vec<Insert<'b>> {
+    let mut visitor = InsertVisitor {
+        cx,
+        check,
+        inserts: Vec::new(),
+    };
+    visitor.visit_expr(branch);
+    visitor.inserts
+}
+
+/// The snippet of `branch` with the calls to `insert` made on the vacant entry.
+/// The key is absent, so the `Option` returned by `insert` is always `None`.
+fn replace_inserts(cx: &LateContext, branch: &Expr, inserts: &[Insert]) -> Option<String> {
+    let mut snip = snippet_opt(cx, branch.span)?;
+    for insert in inserts.iter().rev() {
+        if insert.span.lo() < branch.span.lo() || branch.span.hi() < insert.span.hi() {
+            return None;
+        }
+        let lo = (insert.span.lo().0 - branch.span.lo().0) as usize;
+        let hi = (insert.span.hi().0 - branch.span.lo().0) as usize;
+        let value = Sugg::hir(cx, insert.value, "..");
+        let replacement = if insert.used {
+            format!("{{ entry.insert({}); None }}", value)
+        } else {
+            format!("entry.insert({})", value)
+        };
+        snip = format!("{}{}{}", &snip[..lo], replacement, &snip[hi..]);
     }
+    Some(snip)
+}
 
+/// Returns true if `branch` is a block whose only statement is `call;`.
+fn is_sole_statement(branch: &Expr, call: Span) -> bool {
+    match branch.node {
+        ExprBlock(ref block) => block.expr.is_none() && block.stmts.len() == 1 && match block.stmts[0].node {
+            StmtSemi(ref expr, _) => expr.span == call,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// The block `branch`, if its first statement is `collection.method(key);` or
+/// `collection.method(&key);`, with this statement and the argument of the call.
+fn first_update<'b>(
+    cx: &LateContext,
+    check: &Check,
+    branch: &'b Expr,
+    method: &str,
+) -> Option<(&'b Block, &'b Stmt, &'b Expr)> {
+    if_chain! {
+        if let ExprBlock(ref block) = branch.node;
+        if let Some(stmt) = block.stmts.first();
+        if let StmtSemi(ref call, _) = stmt.node;
+        if let ExprMethodCall(ref path, _, ref params) = call.node;
+        if params.len() == 2 && path.name == method;
+        let key = match params[1].node {
+            ExprAddrOf(_, ref key) if method == "remove" => &**key,
+            _ => &params[1],
+        };
+        let eq = SpanlessEq::new(cx);
+        if eq.eq_expr(check.collection, &params[0]) && eq.eq_expr(check.key, key);
+        then {
+            return Some((block, stmt, &params[1]));
+        }
+    }
     None
 }
 
+/// `if call<test> { rest of block } else { other }` to replace `expr`, or only `call` if the block
+/// has no other statement and there is no `else`.
+fn update_then(
+    cx: &LateContext,
+    expr: &Expr,
+    call: &str,
+    test: &str,
+    block: &Block,
+    stmt: &Stmt,
+    other: Option<&Expr>,
+) -> String {
+    if block.stmts.len() == 1 && block.expr.is_none() && other.is_none() {
+        return statement(cx, expr, call.to_owned());
+    }
+    let rest = snippet(cx, block.span.with_lo(stmt.span.hi()), " .. }");
+    let mut sugg = format!("if {}{} {{{}", call, test, rest);
+    if let Some(other) = other {
+        sugg.push_str(&format!(" else {}", snippet(cx, other.span, "{ .. }")));
+    }
+    sugg
+}
+
 struct InsertVisitor<'a, 'tcx: 'a, 'b> {
     cx: &'a LateContext<'a, 'tcx>,
-    span: Span,
-    ty: &'static str,
-    map: &'b Expr,
-    key: &'b Expr,
-    sole_expr: bool,
+    check: &'a Check<'a>,
+    inserts: Vec<Insert<'b>>,
 }
 
-impl<'a, 'tcx, 'b> Visitor<'tcx> for InsertVisitor<'a, 'tcx, 'b> {
-    fn visit_expr(&mut self, expr: &'tcx Expr) {
+impl<'a, 'tcx, 'b> InsertVisitor<'a, 'tcx, 'b> {
+    fn insert(&self, expr: &'b Expr, used: bool) -> Option<Insert<'b>> {
         if_chain! {
             if let ExprMethodCall(ref path, _, ref params) = expr.node;
             if params.len() == 3;
             if path.name == "insert";
-            if get_item_name(self.cx, self.map) == get_item_name(self.cx, &params[0]);
-            if SpanlessEq::new(self.cx).eq_expr(self.key, &params[1]);
+            let eq = SpanlessEq::new(self.cx);
+            if eq.eq_expr(self.check.collection, &params[0]) && eq.eq_expr(self.check.key, &params[1]);
             then {
-                span_lint_and_then(self.cx, MAP_ENTRY, self.span,
-                                   &format!("usage of `contains_key` followed by `insert` on a `{}`", self.ty), |db| {
-                    if self.sole_expr {
-                        let help = format!("{}.entry({}).or_insert({})",
-                                           snippet(self.cx, self.map.span, "map"),
-                                           snippet(self.cx, params[1].span, ".."),
-                                           snippet(self.cx, params[2].span, ".."));
-
-                        db.span_suggestion(self.span, "consider using", help);
-                    }
-                    else {
-                        let help = format!("{}.entry({})",
-                                           snippet(self.cx, self.map.span, "map"),
-                                           snippet(self.cx, params[1].span, ".."));
-
-                        db.span_suggestion(self.span, "consider using", help);
-                    }
+                return Some(Insert {
+                    span: expr.span,
+                    key: &params[1],
+                    value: &params[2],
+                    used,
                 });
             }
         }
+        None
+    }
+}
+
+impl<'a, 'tcx, 'b> Visitor<'b> for InsertVisitor<'a, 'tcx, 'b> {
+    fn visit_stmt(&mut self, stmt: &'b Stmt) {
+        if let StmtSemi(ref expr, _) = stmt.node {
+            if let Some(insert) = self.insert(expr, false) {
+                self.inserts.push(insert);
+                return;
+            }
+        }
+        walk_stmt(self, stmt);
+    }
 
-        if !self.sole_expr {
-            walk_expr(self, expr);
+    fn visit_expr(&mut self, expr: &'b Expr) {
+        if let Some(insert) = self.insert(expr, true) {
+            self.inserts.push(insert);
+            return;
         }
+        walk_expr(self, expr);
     }
-    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'b> {
         NestedVisitorMap::None
     }
 }
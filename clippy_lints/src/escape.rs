@@ -1,38 +1,38 @@
 use rustc::hir::*;
 use rustc::hir::intravisit as visit;
-use rustc::hir::map::Node::{NodeExpr, NodeStmt};
 use rustc::lint::*;
-use rustc::middle::expr_use_visitor::*;
-use rustc::middle::mem_categorization::{cmt, Categorization};
-use rustc::ty::{self, Ty};
+use rustc::mir::{Local, Location, NullOp, Operand, Place, Rvalue, StatementKind};
+use rustc::ty::Ty;
 use rustc::ty::layout::LayoutOf;
-use rustc::util::nodemap::NodeSet;
+use std::collections::{HashMap, HashSet};
 use syntax::ast::NodeId;
 use syntax::codemap::Span;
 use utils::span_lint;
+use utils::conf::Threshold;
+use utils::mir::{fn_mir, uses_by_location, UseKind};
 
 pub struct Pass {
-    pub too_large_for_stack: u64,
+    pub too_large_for_stack: Threshold,
 }
 
-/// **What it does:** Checks for usage of `Box<T>` where an unboxed `T` would
-/// work fine.
-///
-/// **Why is this bad?** This is an unnecessary allocation, and bad for
-/// performance. It is only necessary to allocate if you wish to move the box
-/// into something.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// fn main() {
-///     let x = Box::new(1);
-///     foo(*x);
-///     println!("{}", *x);
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `Box<T>` where an unboxed `T` would
+    /// work fine.
+    ///
+    /// **Why is this bad?** This is an unnecessary allocation, and bad for
+    /// performance. It is only necessary to allocate if you wish to move the box
+    /// into something.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn main() {
+    ///     let x = Box::new(1);
+    ///     foo(*x);
+    ///     println!("{}", *x);
+    /// }
+    /// ```
     pub BOXED_LOCAL,
     perf,
     "using `Box<T>` where unnecessary"
@@ -42,12 +42,6 @@ fn is_non_trait_box(ty: Ty) -> bool {
     ty.is_box() && !ty.boxed_ty().is_trait()
 }
 
-struct EscapeDelegate<'a, 'tcx: 'a> {
-    cx: &'a LateContext<'a, 'tcx>,
-    set: NodeSet,
-    too_large_for_stack: u64,
-}
-
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
         lint_array!(BOXED_LOCAL)
@@ -58,116 +52,98 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_fn(
         &mut self,
         cx: &LateContext<'a, 'tcx>,
-        _: visit::FnKind<'tcx>,
+        kind: visit::FnKind<'tcx>,
         _: &'tcx FnDecl,
-        body: &'tcx Body,
-        _: Span,
+        _: &'tcx Body,
+        span: Span,
         node_id: NodeId,
     ) {
-        let fn_def_id = cx.tcx.hir.local_def_id(node_id);
-        let mut v = EscapeDelegate {
-            cx,
-            set: NodeSet(),
-            too_large_for_stack: self.too_large_for_stack,
+        let mir = match fn_mir(cx, node_id) {
+            Some(mir) => mir,
+            None => return,
+        };
+        let mir = &*mir;
+        let too_large_for_stack = self.too_large_for_stack.at(cx.sess().codemap(), span);
+        let is_local_box = |local: Local| {
+            let ty = mir.local_decls[local].ty;
+            is_non_trait_box(ty) && !is_large_box(cx, ty, too_large_for_stack)
         };
 
-        let region_scope_tree = &cx.tcx.region_scope_tree(fn_def_id);
-        ExprUseVisitor::new(&mut v, cx.tcx, cx.param_env, region_scope_tree, cx.tables, None).consume_body(body);
-
-        for node in v.set {
-            span_lint(
-                cx,
-                BOXED_LOCAL,
-                cx.tcx.hir.span(node),
-                "local variable doesn't need to be boxed here",
-            );
-        }
-    }
-}
-
-impl<'a, 'tcx> Delegate<'tcx> for EscapeDelegate<'a, 'tcx> {
-    fn consume(&mut self, _: NodeId, _: Span, cmt: cmt<'tcx>, mode: ConsumeMode) {
-        if let Categorization::Local(lid) = cmt.cat {
-            if let Move(DirectRefMove) = mode {
-                // moved out or in. clearly can't be localized
-                self.set.remove(&lid);
-            }
+        // the boxes come from `box` expressions and from the arguments, except the ones of closures
+        let mut boxes = Vec::new();
+        if !matches!(kind, visit::FnKind::Closure(_)) {
+            boxes.extend(mir.args_iter().filter(|&arg| is_local_box(arg)));
         }
-    }
-    fn matched_pat(&mut self, _: &Pat, _: cmt<'tcx>, _: MatchMode) {}
-    fn consume_pat(&mut self, consume_pat: &Pat, cmt: cmt<'tcx>, _: ConsumeMode) {
-        let map = &self.cx.tcx.hir;
-        if map.is_argument(consume_pat.id) {
-            // Skip closure arguments
-            if let Some(NodeExpr(..)) = map.find(map.get_parent_node(consume_pat.id)) {
-                return;
-            }
-            if is_non_trait_box(cmt.ty) && !self.is_large_box(cmt.ty) {
-                self.set.insert(consume_pat.id);
-            }
-            return;
-        }
-        if let Categorization::Rvalue(..) = cmt.cat {
-            if let Some(NodeStmt(st)) = map.find(map.get_parent_node(cmt.id)) {
-                if let StmtDecl(ref decl, _) = st.node {
-                    if let DeclLocal(ref loc) = decl.node {
-                        if let Some(ref ex) = loc.init {
-                            if let ExprBox(..) = ex.node {
-                                if is_non_trait_box(cmt.ty) && !self.is_large_box(cmt.ty) {
-                                    // let x = box (...)
-                                    self.set.insert(consume_pat.id);
-                                }
-                                // TODO Box::new
-                                // TODO vec![]
-                                // TODO "foo".to_owned() and friends
-                            }
-                        }
+        // `let y = x` moves the box from `x` to `y`
+        let mut renames = HashMap::new();
+        let mut known = HashSet::new();
+        for (bb, data) in mir.basic_blocks().iter_enumerated() {
+            for (index, stmt) in data.statements.iter().enumerate() {
+                if let StatementKind::Assign(Place::Local(dest), ref rvalue) = stmt.kind {
+                    let location = Location {
+                        block: bb,
+                        statement_index: index,
+                    };
+                    match *rvalue {
+                        Rvalue::NullaryOp(NullOp::Box, _) if is_local_box(dest) => {
+                            boxes.push(dest);
+                            known.insert(location);
+                        },
+                        Rvalue::Use(Operand::Move(Place::Local(source))) if is_local_box(source) => {
+                            renames.insert(source, dest);
+                            known.insert(location);
+                        },
+                        _ => (),
                     }
                 }
             }
         }
-        if let Categorization::Local(lid) = cmt.cat {
-            if self.set.contains(&lid) {
-                // let y = x where x is known
-                // remove x, insert y
-                self.set.insert(consume_pat.id);
-                self.set.remove(&lid);
+
+        // the box escapes if the whole local is moved, borrowed or assigned, dropping it or using
+        // its content is fine
+        let mut escaping = HashSet::new();
+        for (location, uses) in uses_by_location(mir) {
+            if !known.contains(&location) {
+                escaping.extend(
+                    uses.iter()
+                        .filter(|u| u.whole && u.kind != UseKind::Drop)
+                        .map(|u| u.local),
+                );
             }
         }
-    }
-    fn borrow(&mut self, _: NodeId, _: Span, cmt: cmt<'tcx>, _: ty::Region, _: ty::BorrowKind, loan_cause: LoanCause) {
-        if let Categorization::Local(lid) = cmt.cat {
-            match loan_cause {
-                // x.foo()
-                // Used without autodereffing (i.e. x.clone())
-                LoanCause::AutoRef |
 
-                // &x
-                // foo(&x) where no extra autoreffing is happening
-                LoanCause::AddrOf |
-
-                // `match x` can move
-                LoanCause::MatchDiscriminant => {
-                    self.set.remove(&lid);
+        boxes.sort();
+        for start in boxes {
+            // follow the renames to the last variable holding the box
+            let mut local = start;
+            let mut escapes = escaping.contains(&local);
+            let mut seen = HashSet::new();
+            while let Some(&dest) = renames.get(&local) {
+                if !seen.insert(dest) {
+                    break;
                 }
-
-                // do nothing for matches, etc. These can't escape
-                _ => {}
+                local = dest;
+                escapes |= escaping.contains(&local);
+            }
+            let decl = &mir.local_decls[local];
+            if !escapes && decl.name.is_some() {
+                span_lint(
+                    cx,
+                    BOXED_LOCAL,
+                    decl.source_info.span,
+                    "local variable doesn't need to be boxed here",
+                );
             }
         }
     }
-    fn decl_without_init(&mut self, _: NodeId, _: Span) {}
-    fn mutate(&mut self, _: NodeId, _: Span, _: cmt<'tcx>, _: MutateMode) {}
 }
 
-impl<'a, 'tcx> EscapeDelegate<'a, 'tcx> {
-    fn is_large_box(&self, ty: Ty<'tcx>) -> bool {
-        // Large types need to be boxed to avoid stack
-        // overflows.
-        if ty.is_box() {
-            self.cx.layout_of(ty.boxed_ty()).ok().map_or(0, |l| l.size.bytes()) > self.too_large_for_stack
-        } else {
-            false
-        }
+fn is_large_box<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>, too_large_for_stack: u64) -> bool {
+    // Large types need to be boxed to avoid stack
+    // overflows.
+    if ty.is_box() {
+        cx.layout_of(ty.boxed_ty()).ok().map_or(0, |l| l.size.bytes()) > too_large_for_stack
+    } else {
+        false
     }
 }
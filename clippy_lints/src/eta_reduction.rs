@@ -2,27 +2,28 @@ use rustc::lint::*;
 use rustc::ty;
 use rustc::hir::*;
 use utils::{is_adjusted, iter_input_pats, snippet_opt, span_lint_and_then};
+use utils::{Applicability, DiagnosticBuilderApplicabilityExt};
 
 #[allow(missing_copy_implementations)]
 pub struct EtaPass;
 
 
-/// **What it does:** Checks for closures which just call another function where
-/// the function can be called directly. `unsafe` functions or calls where types
-/// get adjusted are ignored.
-///
-/// **Why is this bad?** Needlessly creating a closure adds code for no benefit
-/// and gives the optimizer more work.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// xs.map(|x| foo(x))
-/// ```
-/// where `foo(_)` is a plain function that takes the exact argument type of
-/// `x`.
 declare_clippy_lint! {
+    /// **What it does:** Checks for closures which just call another function where
+    /// the function can be called directly. `unsafe` functions or calls where types
+    /// get adjusted are ignored.
+    ///
+    /// **Why is this bad?** Needlessly creating a closure adds code for no benefit
+    /// and gives the optimizer more work.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// xs.map(|x| foo(x))
+    /// ```
+    /// where `foo(_)` is a plain function that takes the exact argument type of
+    /// `x`.
     pub REDUNDANT_CLOSURE,
     style,
     "redundant closures, i.e. `|a| foo(a)` (which can be written as just `foo`)"
@@ -91,7 +92,12 @@ fn check_closure(cx: &LateContext, expr: &Expr) {
             }
             span_lint_and_then(cx, REDUNDANT_CLOSURE, expr.span, "redundant closure found", |db| {
                 if let Some(snippet) = snippet_opt(cx, caller.span) {
-                    db.span_suggestion(expr.span, "remove closure as shown", snippet);
+                    db.span_suggestion_with_applicability(
+                        expr.span,
+                        "remove closure as shown",
+                        snippet,
+                        Applicability::MachineApplicable,
+                    );
                 }
             });
         }
@@ -2,46 +2,99 @@ use rustc::hir::*;
 use rustc::lint::*;
 use rustc::ty;
 use syntax::ast::LitKind;
+use syntax::codemap::Span;
 use utils::paths;
-use utils::{in_macro, is_expn_of, match_def_path, match_type, opt_def_id, resolve_node, snippet, span_lint_and_then, walk_ptrs_ty};
-
-/// **What it does:** Checks for the use of `format!("string literal with no
-/// argument")` and `format!("{}", foo)` where `foo` is a string.
-///
-/// **Why is this bad?** There is no point of doing that. `format!("too")` can
-/// be replaced by `"foo".to_owned()` if you really need a `String`. The even
-/// worse `&format!("foo")` is often encountered in the wild. `format!("{}",
-/// foo)` can be replaced by `foo.clone()` if `foo: String` or `foo.to_owned()`
-/// if `foo: &str`.
-///
-/// **Known problems:** None.
-///
-/// **Examples:**
-/// ```rust
-/// format!("foo")
-/// format!("{}", foo)
-/// ```
+use utils::{in_macro, is_expn_of, is_expn_of_any, match_def_path, match_type, opt_def_id, resolve_node, snippet,
+            span_help_and_lint, span_lint_and_then, walk_ptrs_ty};
+use utils::{Applicability, DiagnosticBuilderApplicabilityExt};
+
 declare_clippy_lint! {
+    /// **What it does:** Checks for the use of `format!("string literal with no
+    /// argument")` and `format!("{}", foo)` where `foo` is a string, also through
+    /// the macros of the `format-macros` configuration that call `format!`.
+    ///
+    /// **Why is this bad?** There is no point of doing that. `format!("too")` can
+    /// be replaced by `"foo".to_owned()` if you really need a `String`. The even
+    /// worse `&format!("foo")` is often encountered in the wild. `format!("{}",
+    /// foo)` can be replaced by `foo.clone()` if `foo: String` or `foo.to_owned()`
+    /// if `foo: &str`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Examples:**
+    /// ```rust
+    /// format!("foo")
+    /// format!("{}", foo)
+    /// ```
     pub USELESS_FORMAT,
     complexity,
     "useless use of `format!`"
 }
 
-#[derive(Copy, Clone, Debug)]
-pub struct Pass;
+declare_clippy_lint! {
+    /// **What it does:** Checks for named arguments of format strings, like
+    /// `x` in `format!("{}", x = 1)`, that are not referenced by their name
+    /// in the format string.
+    ///
+    /// **Why is this bad?** A named argument that is never used is an error,
+    /// so the argument is used by its position instead, most likely by
+    /// mistake: the name has no effect and changing the order of the
+    /// arguments silently changes the output.
+    ///
+    /// **Known problems:** The format string is only found when it is written
+    /// as a string literal in the macro call.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// println!("{} is {}", name, age = 42);
+    /// ```
+    pub NAMED_ARGUMENT_USED_POSITIONALLY,
+    style,
+    "named argument of a format string only used by its position"
+}
+
+#[derive(Clone, Debug)]
+pub struct Pass {
+    /// The macros of the `format-macros` configuration.
+    format_macros: Vec<String>,
+}
+
+impl Pass {
+    pub fn new(format_macros: Vec<String>) -> Self {
+        Self { format_macros }
+    }
+}
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array![USELESS_FORMAT]
+        lint_array![USELESS_FORMAT, NAMED_ARGUMENT_USED_POSITIONALLY]
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
-        if let Some(span) = is_expn_of(expr.span, "format") {
-            if in_macro(span) {
-                return;
+        if_chain! {
+            if let ExprCall(ref fun, ref args) = expr.node;
+            if let ExprPath(ref qpath) = fun.node;
+            if let Some(fun_def_id) = opt_def_id(resolve_node(cx, qpath, fun.hir_id));
+            if match_def_path(cx.tcx, fun_def_id, &paths::FMT_ARGUMENTS_NEWV1) ||
+               match_def_path(cx.tcx, fun_def_id, &paths::FMT_ARGUMENTS_NEWV1FORMATTED);
+            then {
+                check_named_arguments(cx, expr, args);
             }
+        }
+
+        if let Some(span) = is_expn_of(expr.span, "format") {
+            // `format!` called by a configured macro, eg. `ui_msg!("{}", msg)`, is linted as that macro
+            let (span, name) = if !in_macro(span) {
+                (span, "format")
+            } else {
+                match is_expn_of_any(cx, expr.span, &self.format_macros) {
+                    Some((span, name)) if !in_macro(span) => (span, name),
+                    _ => return,
+                }
+            };
+            let msg = format!("useless use of `{}!`", name);
             match expr.node {
                 // `format!("{}", foo)` expansion
                 ExprCall(ref fun, ref args) => {
@@ -57,8 +110,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                         if check_arg_is_display(cx, &args[1]);
                         then {
                             let sugg = format!("{}.to_string()", snippet(cx, expr.span, "<expr>").into_owned());
-                            span_lint_and_then(cx, USELESS_FORMAT, span, "useless use of `format!`", |db| {
-                                db.span_suggestion(expr.span, "consider using .to_string()", sugg);
+                            span_lint_and_then(cx, USELESS_FORMAT, span, &msg, |db| {
+                                db.span_suggestion_with_applicability(
+                                    expr.span,
+                                    "consider using .to_string()",
+                                    sugg,
+                                    Applicability::MachineApplicable,
+                                );
                             });
                         }
                     }
@@ -67,8 +125,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 ExprMatch(ref matchee, _, _) => if let ExprTup(ref tup) = matchee.node {
                     if tup.is_empty() {
                         let sugg = format!("{}.to_string()", snippet(cx, expr.span, "<expr>").into_owned());
-                        span_lint_and_then(cx, USELESS_FORMAT, span, "useless use of `format!`", |db| {
-                            db.span_suggestion(span, "consider using .to_string()", sugg);
+                        span_lint_and_then(cx, USELESS_FORMAT, span, &msg, |db| {
+                            db.span_suggestion_with_applicability(
+                                span,
+                                "consider using .to_string()",
+                                sugg,
+                                Applicability::MachineApplicable,
+                            );
                         });
                     }
                 },
@@ -125,3 +188,143 @@ fn check_arg_is_display(cx: &LateContext, expr: &Expr) -> bool {
 
     false
 }
+
+/// Checks the named arguments of an `Arguments::new_v1` call, `args` are the pieces of the format
+/// string and the arguments. The names are lost in the expansion so they are found in the source.
+fn check_named_arguments(cx: &LateContext, expr: &Expr, args: &HirVec<Expr>) {
+    if_chain! {
+        if args.len() >= 2;
+        if let ExprAddrOf(_, ref match_expr) = args[1].node;
+        if let ExprMatch(ref matchee, _, _) = match_expr.node;
+        if let ExprTup(ref tup) = matchee.node;
+        if !tup.is_empty();
+        then {
+            // the call of the outermost macro, as written in the source
+            let call = expr.span.source_callsite();
+            let spans = tup.iter()
+                .map(|arg| match arg.node {
+                    ExprAddrOf(_, ref arg) => arg.span,
+                    _ => arg.span,
+                })
+                .collect::<Vec<_>>();
+            if spans.iter().any(|&span| in_macro(span) || !call.contains(span)) {
+                return;
+            }
+            let codemap = cx.sess().codemap();
+            let before = match codemap.span_to_snippet(call.until(spans[0])) {
+                Ok(before) => before,
+                Err(_) => return,
+            };
+            let (fmt, end) = match last_str_lit(&before) {
+                Some(lit) => lit,
+                None => return,
+            };
+            let used = referenced_names(fmt);
+            for (i, &span) in spans.iter().enumerate() {
+                // the text before the argument, eg. `, x = `
+                let sep = if i == 0 {
+                    Some(before[end..].to_owned())
+                } else {
+                    codemap.span_to_snippet(spans[i - 1].between(span)).ok()
+                };
+                if let Some(name) = sep.as_ref().and_then(|sep| argument_name(sep)) {
+                    if !used.contains(&name) {
+                        span_help_and_lint(
+                            cx,
+                            NAMED_ARGUMENT_USED_POSITIONALLY,
+                            span,
+                            &format!("named argument `{}` is not used by name", name),
+                            &format!("use `{{{}}}` in the format string or remove the name", name),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The content of the last string literal of `text` and the position of its end.
+fn last_str_lit(text: &str) -> Option<(&str, usize)> {
+    let bytes = text.as_bytes();
+    let mut last = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'"' {
+            let start = i + 1;
+            i = start;
+            while i < bytes.len() && bytes[i] != b'"' {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            if i >= bytes.len() {
+                return None;
+            }
+            last = Some((&text[start..i], i + 1));
+        } else if bytes[i] == b'r' && (i == 0 || !is_ident_byte(bytes[i - 1])) {
+            // `r"..."`, `r#"..."#`...
+            let hashes = bytes[i + 1..].iter().take_while(|&&b| b == b'#').count();
+            if bytes.get(i + 1 + hashes) == Some(&b'"') {
+                let start = i + 2 + hashes;
+                let close = format!("\"{}", "#".repeat(hashes));
+                let len = text[start..].find(&close)?;
+                last = Some((&text[start..start + len], start + len + close.len()));
+                i = start + len + close.len() - 1;
+            }
+        }
+        i += 1;
+    }
+    last
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b == b'_' || b.is_ascii_alphanumeric()
+}
+
+/// The identifier at the end of `text`.
+fn trailing_ident(text: &str) -> &str {
+    text.rsplit(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()
+        .unwrap_or("")
+}
+
+/// The names a format string uses as arguments (`{x}`), widths (`{:x$}`) or precisions (`{:.x$}`).
+fn referenced_names(fmt: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = fmt;
+    while let Some(open) = rest.find('{') {
+        rest = &rest[open + 1..];
+        // `{{` is an escaped brace
+        if rest.starts_with('{') {
+            rest = &rest[1..];
+            continue;
+        }
+        let close = match rest.find('}') {
+            Some(close) => close,
+            None => break,
+        };
+        let (arg, spec) = match rest[..close].find(':') {
+            Some(colon) => (&rest[..colon], &rest[colon + 1..close]),
+            None => (&rest[..close], ""),
+        };
+        names.push(arg.trim());
+        for (dollar, _) in spec.match_indices('$') {
+            // an identifier cannot start with a digit, eg. the `0` flag in `{:0x$}`
+            names.push(trailing_ident(&spec[..dollar]).trim_left_matches(|c: char| c.is_numeric()));
+        }
+        rest = &rest[close + 1..];
+    }
+    names
+}
+
+/// The name of a named argument from the text before it, eg. `x` in `, x = `.
+fn argument_name(sep: &str) -> Option<&str> {
+    let sep = sep.trim_right();
+    if !sep.ends_with('=') || sep.ends_with("==") {
+        return None;
+    }
+    let name = trailing_ident(sep[..sep.len() - 1].trim_right());
+    if name.is_empty() || name.starts_with(|c: char| c.is_numeric()) {
+        None
+    } else {
+        Some(name)
+    }
+}
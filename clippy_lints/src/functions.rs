@@ -8,59 +8,61 @@ use syntax::ast;
 use syntax::abi::Abi;
 use syntax::codemap::Span;
 use utils::{iter_input_pats, span_lint, type_is_unsafe_function};
+use utils::conf::Threshold;
+use utils::metrics::{self, Metric};
 
-/// **What it does:** Checks for functions with too many parameters.
-///
-/// **Why is this bad?** Functions with lots of parameters are considered bad
-/// style and reduce readability (“what does the 5th parameter mean?”). Consider
-/// grouping some parameters into a new type.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// fn foo(x: u32, y: u32, name: &str, c: Color, w: f32, h: f32, a: f32, b:
-/// f32) { .. }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for functions with too many parameters.
+    ///
+    /// **Why is this bad?** Functions with lots of parameters are considered bad
+    /// style and reduce readability (“what does the 5th parameter mean?”). Consider
+    /// grouping some parameters into a new type.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// fn foo(x: u32, y: u32, name: &str, c: Color, w: f32, h: f32, a: f32, b:
+    /// f32) { .. }
+    /// ```
     pub TOO_MANY_ARGUMENTS,
     complexity,
     "functions with too many arguments"
 }
 
-/// **What it does:** Checks for public functions that dereferences raw pointer
-/// arguments but are not marked unsafe.
-///
-/// **Why is this bad?** The function should probably be marked `unsafe`, since
-/// for an arbitrary raw pointer, there is no way of telling for sure if it is
-/// valid.
-///
-/// **Known problems:**
-///
-/// * It does not check functions recursively so if the pointer is passed to a
-/// private non-`unsafe` function which does the dereferencing, the lint won't
-/// trigger.
-/// * It only checks for arguments whose type are raw pointers, not raw pointers
-/// got from an argument in some other way (`fn foo(bar: &[*const u8])` or
-/// `some_argument.get_raw_ptr()`).
-///
-/// **Example:**
-/// ```rust
-/// pub fn foo(x: *const u8) { println!("{}", unsafe { *x }); }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for public functions that dereferences raw pointer
+    /// arguments but are not marked unsafe.
+    ///
+    /// **Why is this bad?** The function should probably be marked `unsafe`, since
+    /// for an arbitrary raw pointer, there is no way of telling for sure if it is
+    /// valid.
+    ///
+    /// **Known problems:**
+    ///
+    /// * It does not check functions recursively so if the pointer is passed to a
+    /// private non-`unsafe` function which does the dereferencing, the lint won't
+    /// trigger.
+    /// * It only checks for arguments whose type are raw pointers, not raw pointers
+    /// got from an argument in some other way (`fn foo(bar: &[*const u8])` or
+    /// `some_argument.get_raw_ptr()`).
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub fn foo(x: *const u8) { println!("{}", unsafe { *x }); }
+    /// ```
     pub NOT_UNSAFE_PTR_ARG_DEREF,
     correctness,
     "public functions dereferencing raw pointer arguments but not marked `unsafe`"
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct Functions {
-    threshold: u64,
+    threshold: Threshold,
 }
 
 impl Functions {
-    pub fn new(threshold: u64) -> Self {
+    pub fn new(threshold: Threshold) -> Self {
         Self {
             threshold,
         }
@@ -97,6 +99,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
             hir::intravisit::FnKind::Closure(_) => return,
         };
 
+        metrics::record(cx, nodeid, Metric::Arguments, decl.inputs.len() as u64);
+        metrics::record_lines(cx, nodeid);
+
         // don't warn for implementations, it's not their fault
         if !is_impl {
             // don't lint extern functions decls, it's not their fault either
@@ -117,9 +122,16 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
                 self.check_arg_number(cx, &sig.decl, item.span);
             }
 
-            if let hir::TraitMethod::Provided(eid) = *eid {
-                let body = cx.tcx.hir.body(eid);
-                self.check_raw_ptr(cx, sig.unsafety, &sig.decl, body, item.id);
+            // the methods with a body are measured by `check_fn`
+            match *eid {
+                hir::TraitMethod::Provided(eid) => {
+                    let body = cx.tcx.hir.body(eid);
+                    self.check_raw_ptr(cx, sig.unsafety, &sig.decl, body, item.id);
+                },
+                hir::TraitMethod::Required(_) => {
+                    metrics::record(cx, item.id, Metric::Arguments, sig.decl.inputs.len() as u64);
+                    metrics::record_lines(cx, item.id);
+                },
             }
         }
     }
@@ -128,12 +140,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
 impl<'a, 'tcx> Functions {
     fn check_arg_number(&self, cx: &LateContext, decl: &hir::FnDecl, span: Span) {
         let args = decl.inputs.len() as u64;
-        if args > self.threshold {
+        let threshold = self.threshold.at(cx.sess().codemap(), span);
+        if args > threshold {
             span_lint(
                 cx,
                 TOO_MANY_ARGUMENTS,
                 span,
-                &format!("this function has too many arguments ({}/{})", args, self.threshold),
+                &format!("this function has too many arguments ({}/{})", args, threshold),
             );
         }
     }
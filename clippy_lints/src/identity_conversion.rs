@@ -2,20 +2,20 @@ use rustc::lint::*;
 use rustc::hir::*;
 use syntax::ast::NodeId;
 use utils::{in_macro, match_def_path, match_trait_method, same_tys, snippet, span_lint_and_then};
-use utils::{opt_def_id, paths, resolve_node};
+use utils::{opt_def_id, paths, resolve_node, Applicability, DiagnosticBuilderApplicabilityExt};
 
-/// **What it does:** Checks for always-identical `Into`/`From` conversions.
-///
-/// **Why is this bad?** Redundant code.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// // format!() returns a `String`
-/// let s: String = format!("hello").into();
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for always-identical `Into`/`From` conversions.
+    ///
+    /// **Why is this bad?** Redundant code.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// // format!() returns a `String`
+    /// let s: String = format!("hello").into();
+    /// ```
     pub IDENTITY_CONVERSION,
     complexity,
     "using always-identical `Into`/`From` conversions"
@@ -62,7 +62,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IdentityConversion {
                     if same_tys(cx, a, b) {
                         let sugg = snippet(cx, args[0].span, "<expr>").into_owned();
                         span_lint_and_then(cx, IDENTITY_CONVERSION, e.span, "identical conversion", |db| {
-                            db.span_suggestion(e.span, "consider removing `.into()`", sugg);
+                            db.span_suggestion_with_applicability(
+                                e.span,
+                                "consider removing `.into()`",
+                                sugg,
+                                Applicability::MachineApplicable,
+                            );
                         });
                     }
                 }
@@ -77,7 +82,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IdentityConversion {
                             let sugg = snippet(cx, args[0].span, "<expr>").into_owned();
                             let sugg_msg = format!("consider removing `{}()`", snippet(cx, path.span, "From::from"));
                             span_lint_and_then(cx, IDENTITY_CONVERSION, e.span, "identical conversion", |db| {
-                                db.span_suggestion(e.span, &sugg_msg, sugg);
+                                db.span_suggestion_with_applicability(
+                                    e.span,
+                                    &sugg_msg,
+                                    sugg,
+                                    Applicability::MachineApplicable,
+                                );
                             });
                         }
                     }
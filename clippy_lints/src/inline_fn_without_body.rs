@@ -5,22 +5,23 @@ use rustc::hir::*;
 use syntax::ast::{Attribute, Name};
 use utils::span_lint_and_then;
 use utils::sugg::DiagnosticBuilderExt;
+use utils::Applicability;
 
-/// **What it does:** Checks for `#[inline]` on trait methods without bodies
-///
-/// **Why is this bad?** Only implementations of trait methods may be inlined.
-/// The inline attribute is ignored for trait methods without bodies.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// trait Animal {
-///     #[inline]
-///     fn name(&self) -> &'static str;
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for `#[inline]` on trait methods without bodies
+    ///
+    /// **Why is this bad?** Only implementations of trait methods may be inlined.
+    /// The inline attribute is ignored for trait methods without bodies.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// trait Animal {
+    ///     #[inline]
+    ///     fn name(&self) -> &'static str;
+    /// }
+    /// ```
     pub INLINE_FN_WITHOUT_BODY,
     correctness,
     "use of `#[inline]` on trait methods without bodies"
@@ -55,7 +56,7 @@ fn check_attrs(cx: &LateContext, name: &Name, attrs: &[Attribute]) {
             attr.span,
             &format!("use of `#[inline]` on trait method `{}` which has no body", name),
             |db| {
-                db.suggest_remove_item(cx, attr.span, "remove");
+                db.suggest_remove_item(cx, attr.span, "remove", Applicability::MachineApplicable);
             },
         );
     }
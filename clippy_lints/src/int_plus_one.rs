@@ -3,26 +3,26 @@
 use rustc::lint::*;
 use syntax::ast::*;
 
-use utils::{snippet_opt, span_lint_and_then};
+use utils::{snippet_opt, span_lint_and_then, Applicability, DiagnosticBuilderApplicabilityExt};
 
-/// **What it does:** Checks for usage of `x >= y + 1` or `x - 1 >= y` (and `<=`) in a block
-///
-///
-/// **Why is this bad?** Readability -- better to use `> y` instead of `>= y + 1`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// x >= y + 1
-/// ```
-///
-/// Could be written:
-///
-/// ```rust
-/// x > y
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `x >= y + 1` or `x - 1 >= y` (and `<=`) in a block
+    ///
+    ///
+    /// **Why is this bad?** Readability -- better to use `> y` instead of `>= y + 1`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// x >= y + 1
+    /// ```
+    ///
+    /// Could be written:
+    ///
+    /// ```rust
+    /// x > y
+    /// ```
     pub INT_PLUS_ONE,
     complexity,
     "instead of using x >= y + 1, use x > y"
@@ -151,7 +151,12 @@ impl IntPlusOne {
 
     fn emit_warning(&self, cx: &EarlyContext, block: &Expr, recommendation: String) {
         span_lint_and_then(cx, INT_PLUS_ONE, block.span, "Unnecessary `>= y + 1` or `x - 1 >=`", |db| {
-            db.span_suggestion(block.span, "change `>= y + 1` to `> y` as shown", recommendation);
+            db.span_suggestion_with_applicability(
+                block.span,
+                "change `>= y + 1` to `> y` as shown",
+                recommendation,
+                Applicability::MachineApplicable,
+            );
         });
     }
 }
@@ -3,17 +3,17 @@ use rustc::ty;
 use rustc::hir::*;
 use utils::{match_def_path, opt_def_id, paths, span_help_and_lint};
 
-/// **What it does:** Checks for creation of references to zeroed or uninitialized memory.
-///
-/// **Why is this bad?** Creation of null references is undefined behavior.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let bad_ref: &usize = std::mem::zeroed();
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for creation of references to zeroed or uninitialized memory.
+    ///
+    /// **Why is this bad?** Creation of null references is undefined behavior.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let bad_ref: &usize = std::mem::zeroed();
+    /// ```
     pub INVALID_REF,
     correctness,
     "creation of invalid reference"
@@ -2,38 +2,39 @@
 
 use rustc::lint::*;
 use rustc::hir::*;
-use utils::{snippet_opt, span_lint_and_then};
+use utils::{snippet_opt, span_lint_and_then, Applicability, DiagnosticBuilderApplicabilityExt};
 use rustc::ty::layout::LayoutOf;
+use utils::conf::Threshold;
 
-/// **What it does:** Checks for large size differences between variants on
-/// `enum`s.
-///
-/// **Why is this bad?** Enum size is bounded by the largest variant. Having a
-/// large variant
-/// can penalize the memory layout of that enum.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// enum Test {
-///    A(i32),
-///    B([i32; 8000]),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for large size differences between variants on
+    /// `enum`s.
+    ///
+    /// **Why is this bad?** Enum size is bounded by the largest variant. Having a
+    /// large variant
+    /// can penalize the memory layout of that enum.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// enum Test {
+    ///    A(i32),
+    ///    B([i32; 8000]),
+    /// }
+    /// ```
     pub LARGE_ENUM_VARIANT,
     perf,
     "large size difference between variants on an enum"
 }
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct LargeEnumVariant {
-    maximum_size_difference_allowed: u64,
+    maximum_size_difference_allowed: Threshold,
 }
 
 impl LargeEnumVariant {
-    pub fn new(maximum_size_difference_allowed: u64) -> Self {
+    pub fn new(maximum_size_difference_allowed: Threshold) -> Self {
         Self {
             maximum_size_difference_allowed,
         }
@@ -78,7 +79,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
             if let (Some(smallest), Some(largest)) = (smallest_variant, largest_variant) {
                 let difference = largest.0 - smallest.0;
 
-                if difference > self.maximum_size_difference_allowed {
+                if difference > self.maximum_size_difference_allowed.at(cx.sess().codemap(), item.span) {
                     let (i, variant) = largest.1;
 
                     span_lint_and_then(
@@ -95,11 +96,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeEnumVariant {
                                     VariantData::Unit(_) => unreachable!(),
                                 };
                                 if let Some(snip) = snippet_opt(cx, span) {
-                                    db.span_suggestion(
+                                    db.span_suggestion_with_applicability(
                                         span,
                                         "consider boxing the large fields to reduce the total size of the \
                                          enum",
                                         format!("Box<{}>", snip),
+                                        Applicability::MaybeIncorrect,
                                     );
                                     return;
                                 }
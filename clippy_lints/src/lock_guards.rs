@@ -0,0 +1,268 @@
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::{Body, FnDecl};
+use rustc::lint::*;
+use rustc::mir::{BasicBlock, Location, Mir, Operand, Place, Rvalue, StatementKind, TerminatorKind};
+use rustc::ty::{self, Ty};
+use std::collections::{HashMap, HashSet};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+use utils::{in_macro, match_def_path, match_type, paths, span_lint_and_then};
+use utils::mir::{fn_mir, maybe_initialized, update_initialized, uses_by_location, UseKind};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for calls locking a `Mutex` or a `RwLock`, or
+    /// borrowing a `RefCell`, while a guard of the same value is still alive in
+    /// the function.
+    ///
+    /// **Why is this bad?** Locking a `Mutex` twice on the same thread deadlocks,
+    /// and borrowing a `RefCell` mutably while it is borrowed panics. This often
+    /// happens with the temporary guard of a `while let` or `match` scrutinee,
+    /// which lives until the end of the loop or the `match`.
+    ///
+    /// **Known problems:** The values are only recognized when they are reached
+    /// the same way, eg. `self.queue` twice. Guards passed to other functions are
+    /// not followed.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// while let Some(job) = queue.lock().unwrap().pop() {
+    ///     queue.lock().unwrap().push(job.next());
+    /// }
+    /// ```
+    pub DOUBLE_LOCK,
+    correctness,
+    "locking a `Mutex`, a `RwLock` or a `RefCell` while a guard of it is alive"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for blocking calls, like `thread::sleep`,
+    /// `Receiver::recv` or `JoinHandle::join`, while the guard of a `Mutex` or a
+    /// `RwLock` is alive.
+    ///
+    /// **Why is this bad?** The other threads needing the lock wait for the whole
+    /// call, and the program deadlocks if the thread joined or sending to the
+    /// channel needs the lock.
+    ///
+    /// **Known problems:** The blocking functions of other crates are not known.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let mut state = state.lock().unwrap();
+    /// state.last = rx.recv().unwrap();
+    /// ```
+    pub GUARD_ACROSS_BLOCKING_CALL,
+    perf,
+    "blocking call while the guard of a `Mutex` or a `RwLock` is alive"
+}
+
+#[derive(Copy, Clone)]
+pub struct LockGuards;
+
+impl LintPass for LockGuards {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DOUBLE_LOCK, GUARD_ACROSS_BLOCKING_CALL)
+    }
+}
+
+/// A call locking a value.
+#[derive(Clone)]
+struct Lock<'tcx> {
+    /// The value locked, if it is known.
+    place: Option<Place<'tcx>>,
+    /// Whether no other guard of the value can be alive, eg. `Mutex::lock` or `RefCell::borrow_mut`.
+    exclusive: bool,
+    /// Whether the value is shared between threads, ie. not a `RefCell`.
+    sync: bool,
+    span: Span,
+}
+
+/// The methods locking a value, whether they are exclusive, and whether the value is shared between
+/// threads.
+const LOCK_METHODS: [(&[&str], bool, bool); 5] = [
+    (&paths::MUTEX_LOCK, true, true),
+    (&paths::RWLOCK_READ, false, true),
+    (&paths::RWLOCK_WRITE, true, true),
+    (&paths::REFCELL_BORROW, false, false),
+    (&paths::REFCELL_BORROW_MUT, true, false),
+];
+
+const BLOCKING_FUNCTIONS: [&[&str]; 4] = [
+    &paths::THREAD_SLEEP,
+    &paths::RECEIVER_RECV,
+    &paths::RECEIVER_RECV_TIMEOUT,
+    &paths::JOIN_HANDLE_JOIN,
+];
+
+const GUARDS: [&[&str]; 5] = [
+    &paths::MUTEX_GUARD,
+    &paths::RWLOCK_READ_GUARD,
+    &paths::RWLOCK_WRITE_GUARD,
+    &paths::REFCELL_REF,
+    &paths::REFCELL_REF_MUT,
+];
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LockGuards {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        _: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        _: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+        let mir = match fn_mir(cx, node_id) {
+            Some(mir) => mir,
+            None => return,
+        };
+        let mir = &*mir;
+
+        // the locks, and the calls to blocking functions
+        let mut locks = HashMap::new();
+        let mut blocking = HashMap::new();
+        for (bb, data) in mir.basic_blocks().iter_enumerated() {
+            let terminator = data.terminator();
+            if let TerminatorKind::Call { ref func, ref args, .. } = terminator.kind {
+                let def_id = match func.ty(mir, cx.tcx).sty {
+                    ty::TyFnDef(def_id, _) => def_id,
+                    _ => continue,
+                };
+                let location = Location {
+                    block: bb,
+                    statement_index: data.statements.len(),
+                };
+                let span = terminator.source_info.span;
+                if let Some(&(_, exclusive, sync)) = LOCK_METHODS
+                    .iter()
+                    .find(|&&(path, _, _)| match_def_path(cx.tcx, def_id, path))
+                {
+                    let place = args.get(0).and_then(|arg| locked_place(mir, bb, arg));
+                    locks.insert(
+                        location,
+                        Lock {
+                            place,
+                            exclusive,
+                            sync,
+                            span,
+                        },
+                    );
+                } else if BLOCKING_FUNCTIONS.iter().any(|path| match_def_path(cx.tcx, def_id, path)) {
+                    blocking.insert(location, (cx.tcx.item_name(def_id), span));
+                }
+            }
+        }
+        if locks.is_empty() {
+            return;
+        }
+
+        // the locals holding a guard, and the lock it comes from
+        let locations = uses_by_location(mir);
+        let mut guards: HashMap<_, Lock> = HashMap::new();
+        for &(location, ref uses) in &locations {
+            if let Some(lock) = locks.get(&location) {
+                for u in uses.iter().filter(|u| u.kind == UseKind::Assign) {
+                    guards.insert(u.local, lock.clone());
+                }
+            }
+        }
+        // `unwrap`, `match` and the like move the guard to other locals
+        loop {
+            let len = guards.len();
+            for &(_, ref uses) in &locations {
+                let source = uses.iter()
+                    .filter(|u| u.kind == UseKind::Move)
+                    .filter_map(|u| guards.get(&u.local))
+                    .next()
+                    .cloned();
+                if let Some(lock) = source {
+                    for u in uses.iter().filter(|u| u.kind == UseKind::Assign) {
+                        if !guards.contains_key(&u.local) && holds_guard(cx, mir.local_decls[u.local].ty) {
+                            guards.insert(u.local, lock.clone());
+                        }
+                    }
+                }
+            }
+            if guards.len() == len {
+                break;
+            }
+        }
+
+        let entry = maybe_initialized(mir);
+        let mut current: Option<(BasicBlock, HashSet<_>)> = None;
+        for &(location, ref uses) in &locations {
+            if current.as_ref().map_or(true, |&(bb, _)| bb != location.block) {
+                let init = entry.get(&location.block).cloned().unwrap_or_default();
+                current = Some((location.block, init));
+            }
+            let init = &mut current.as_mut().expect("set above").1;
+
+            // the guards alive before the call, once per lock
+            let mut seen = HashSet::new();
+            let alive = init.iter()
+                .filter_map(|local| guards.get(local))
+                .filter(|guard| seen.insert(guard.span))
+                .collect::<Vec<_>>();
+            if let Some(lock) = locks.get(&location) {
+                let conflict = alive.iter().find(|guard| {
+                    lock.place.is_some() && guard.place == lock.place && (guard.exclusive || lock.exclusive)
+                });
+                if let Some(guard) = conflict {
+                    report(cx, DOUBLE_LOCK, lock.span, "this locks a value whose guard is alive", guard.span);
+                }
+            }
+            if let Some(&(ref name, span)) = blocking.get(&location) {
+                if let Some(guard) = alive.iter().find(|guard| guard.sync) {
+                    let msg = format!("calling `{}` while a lock is held", name);
+                    report(cx, GUARD_ACROSS_BLOCKING_CALL, span, &msg, guard.span);
+                }
+            }
+
+            update_initialized(init, uses);
+        }
+    }
+}
+
+fn report(cx: &LateContext, lint: &'static Lint, span: Span, msg: &str, guard: Span) {
+    if in_macro(span) {
+        return;
+    }
+    span_lint_and_then(cx, lint, span, msg, |db| {
+        db.span_note(guard, "the guard is created here");
+    });
+}
+
+/// The value the receiver `arg` of a locking method refers to: `x` for `tmp = &x; lock(tmp)`, and
+/// `*r` for a reference `r`.
+fn locked_place<'tcx>(mir: &Mir<'tcx>, bb: BasicBlock, arg: &Operand<'tcx>) -> Option<Place<'tcx>> {
+    let local = match *arg {
+        Operand::Move(Place::Local(local)) | Operand::Copy(Place::Local(local)) => local,
+        _ => return None,
+    };
+    for stmt in mir[bb].statements.iter().rev() {
+        if let StatementKind::Assign(Place::Local(dest), Rvalue::Ref(_, _, ref place)) = stmt.kind {
+            if dest == local {
+                return Some(place.clone());
+            }
+        }
+    }
+    if mir.local_decls[local].name.is_some() {
+        Some(Place::Local(local).deref())
+    } else {
+        None
+    }
+}
+
+/// Returns true if a value of type `ty` may hold a guard, eg. `MutexGuard` or
+/// `LockResult<MutexGuard>`. References to guards are not guards.
+fn holds_guard(cx: &LateContext, ty: Ty) -> bool {
+    match ty.sty {
+        ty::TyAdt(_, substs) => {
+            GUARDS.iter().any(|path| match_type(cx, ty, path)) || substs.types().any(|ty| holds_guard(cx, ty))
+        },
+        ty::TyTuple(tys) => tys.iter().any(|&ty| holds_guard(cx, ty)),
+        _ => false,
+    }
+}
@@ -5,20 +5,20 @@ use syntax::ast;
 use utils::{get_arg_name, is_adjusted, iter_input_pats, match_qpath, match_trait_method, match_type,
             paths, remove_blocks, snippet, span_help_and_lint, walk_ptrs_ty, walk_ptrs_ty_depth};
 
-/// **What it does:** Checks for mapping `clone()` over an iterator.
-///
-/// **Why is this bad?** It makes the code less readable than using the
-/// `.cloned()` adapter.
-///
-/// **Known problems:** Sometimes `.cloned()` requires stricter trait
-/// bound than `.map(|e| e.clone())` (which works because of the coercion).
-/// See [#498](https://github.com/rust-lang-nursery/rust-clippy/issues/498).
-///
-/// **Example:**
-/// ```rust
-/// x.map(|e| e.clone());
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for mapping `clone()` over an iterator.
+    ///
+    /// **Why is this bad?** It makes the code less readable than using the
+    /// `.cloned()` adapter.
+    ///
+    /// **Known problems:** Sometimes `.cloned()` requires stricter trait
+    /// bound than `.map(|e| e.clone())` (which works because of the coercion).
+    /// See [#498](https://github.com/rust-lang-nursery/rust-clippy/issues/498).
+    ///
+    /// **Example:**
+    /// ```rust
+    /// x.map(|e| e.clone());
+    /// ```
     pub MAP_CLONE,
     style,
     "using `.map(|x| x.clone())` to clone an iterator or option's contents"
@@ -1,4 +1,6 @@
 use rustc::hir::*;
+use rustc::hir::def::{CtorKind, Def};
+use rustc::hir::def_id::DefId;
 use rustc::lint::*;
 use rustc::ty::{self, Ty};
 use std::cmp::Ordering;
@@ -6,162 +8,195 @@ use std::collections::Bound;
 use syntax::ast::LitKind;
 use syntax::codemap::Span;
 use utils::paths;
-use utils::{expr_block, in_external_macro, is_allowed, is_expn_of, match_qpath, match_type, multispan_sugg,
-            remove_blocks, snippet, span_lint_and_sugg, span_lint_and_then, span_note_and_lint, walk_ptrs_ty};
+use utils::{expr_block, in_external_macro, is_allowed, is_expn_of, is_refutable, match_qpath, match_type,
+            multispan_sugg, remove_blocks, snippet, span_lint_and_sugg, span_lint_and_then, span_note_and_lint,
+            walk_ptrs_ty};
 use utils::sugg::Sugg;
+use utils::{Applicability, DiagnosticBuilderApplicabilityExt};
 use consts::{constant, Constant};
 
-/// **What it does:** Checks for matches with a single arm where an `if let`
-/// will usually suffice.
-///
-/// **Why is this bad?** Just readability – `if let` nests less than a `match`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// match x {
-///     Some(ref foo) => bar(foo),
-///     _ => ()
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for matches with a single arm where an `if let`
+    /// will usually suffice.
+    ///
+    /// **Why is this bad?** Just readability – `if let` nests less than a `match`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// match x {
+    ///     Some(ref foo) => bar(foo),
+    ///     _ => ()
+    /// }
+    /// ```
     pub SINGLE_MATCH,
     style,
     "a match statement with a single nontrivial arm (i.e. where the other arm \
      is `_ => {}`) instead of `if let`"
 }
 
-/// **What it does:** Checks for matches with a two arms where an `if let` will
-/// usually suffice.
-///
-/// **Why is this bad?** Just readability – `if let` nests less than a `match`.
-///
-/// **Known problems:** Personal style preferences may differ.
-///
-/// **Example:**
-/// ```rust
-/// match x {
-///     Some(ref foo) => bar(foo),
-///     _ => bar(other_ref),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for matches with a two arms where an `if let` will
+    /// usually suffice.
+    ///
+    /// **Why is this bad?** Just readability – `if let` nests less than a `match`.
+    ///
+    /// **Known problems:** Personal style preferences may differ.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// match x {
+    ///     Some(ref foo) => bar(foo),
+    ///     _ => bar(other_ref),
+    /// }
+    /// ```
     pub SINGLE_MATCH_ELSE,
     pedantic,
     "a match statement with a two arms where the second arm's pattern is a wildcard \
      instead of `if let`"
 }
 
-/// **What it does:** Checks for matches where all arms match a reference,
-/// suggesting to remove the reference and deref the matched expression
-/// instead. It also checks for `if let &foo = bar` blocks.
-///
-/// **Why is this bad?** It just makes the code less readable. That reference
-/// destructuring adds nothing to the code.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// match x {
-///     &A(ref y) => foo(y),
-///     &B => bar(),
-///     _ => frob(&x),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for matches where all arms match a reference,
+    /// suggesting to remove the reference and deref the matched expression
+    /// instead. It also checks for `if let &foo = bar` blocks.
+    ///
+    /// **Why is this bad?** It just makes the code less readable. That reference
+    /// destructuring adds nothing to the code.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// match x {
+    ///     &A(ref y) => foo(y),
+    ///     &B => bar(),
+    ///     _ => frob(&x),
+    /// }
+    /// ```
     pub MATCH_REF_PATS,
     style,
     "a match or `if let` with all arms prefixed with `&` instead of deref-ing the match expression"
 }
 
-/// **What it does:** Checks for matches where match expression is a `bool`. It
-/// suggests to replace the expression with an `if...else` block.
-///
-/// **Why is this bad?** It makes the code less readable.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let condition: bool = true;
-/// match condition {
-///     true => foo(),
-///     false => bar(),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for matches where match expression is a `bool`. It
+    /// suggests to replace the expression with an `if...else` block.
+    ///
+    /// **Why is this bad?** It makes the code less readable.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let condition: bool = true;
+    /// match condition {
+    ///     true => foo(),
+    ///     false => bar(),
+    /// }
+    /// ```
     pub MATCH_BOOL,
     style,
     "a match on a boolean expression instead of an `if..else` block"
 }
 
-/// **What it does:** Checks for overlapping match arms.
-///
-/// **Why is this bad?** It is likely to be an error and if not, makes the code
-/// less obvious.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let x = 5;
-/// match x {
-///     1 ... 10 => println!("1 ... 10"),
-///     5 ... 15 => println!("5 ... 15"),
-///     _ => (),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for overlapping match arms.
+    ///
+    /// **Why is this bad?** It is likely to be an error and if not, makes the code
+    /// less obvious.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x = 5;
+    /// match x {
+    ///     1 ... 10 => println!("1 ... 10"),
+    ///     5 ... 15 => println!("5 ... 15"),
+    ///     _ => (),
+    /// }
+    /// ```
     pub MATCH_OVERLAPPING_ARM,
     style,
     "a match with overlapping arms"
 }
 
-/// **What it does:** Checks for arm which matches all errors with `Err(_)`
-/// and take drastic actions like `panic!`.
-///
-/// **Why is this bad?** It is generally a bad practice, just like
-/// catching all exceptions in java with `catch(Exception)`
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let x : Result(i32, &str) = Ok(3);
-/// match x {
-///     Ok(_) => println!("ok"),
-///     Err(_) => panic!("err"),
-/// }
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for arm which matches all errors with `Err(_)`
+    /// and take drastic actions like `panic!`.
+    ///
+    /// **Why is this bad?** It is generally a bad practice, just like
+    /// catching all exceptions in java with `catch(Exception)`
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x : Result(i32, &str) = Ok(3);
+    /// match x {
+    ///     Ok(_) => println!("ok"),
+    ///     Err(_) => panic!("err"),
+    /// }
+    /// ```
     pub MATCH_WILD_ERR_ARM,
     style,
     "a match with `Err(_)` arm and take drastic actions"
 }
 
-/// **What it does:** Checks for match which is used to add a reference to an
-/// `Option` value.
-///
-/// **Why is this bad?** Using `as_ref()` or `as_mut()` instead is shorter.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let x: Option<()> = None;
-/// let r: Option<&()> = match x {
-///   None => None,
-///   Some(ref v) => Some(v),
-/// };
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for match which is used to add a reference to an
+    /// `Option` value.
+    ///
+    /// **Why is this bad?** Using `as_ref()` or `as_mut()` instead is shorter.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let x: Option<()> = None;
+    /// let r: Option<&()> = match x {
+    ///   None => None,
+    ///   Some(ref v) => Some(v),
+    /// };
+    /// ```
     pub MATCH_AS_REF,
     complexity,
     "a match on an Option value instead of using `as_ref()` or `as_mut`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for wildcard arms, `_` or a binding, in matches on
+    /// enums. The enums of other crates are skipped if they are marked as non
+    /// exhaustive or have a hidden `__` variant.
+    ///
+    /// **Why is this bad?** A variant added to the enum later is silently handled
+    /// by the wildcard arm, instead of being reported by the compiler in every
+    /// `match` which has to handle it.
+    ///
+    /// **Known problems:** A variant matched with a guard, or with a condition on
+    /// its fields, is also listed as matched by the wildcard.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// match color {
+    ///     Color::Red => stop(),
+    ///     _ => go(),
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// match color {
+    ///     Color::Red => stop(),
+    ///     Color::Green | Color::Blue => go(),
+    /// }
+    /// ```
+    pub WILDCARD_ENUM_MATCH_ARM,
+    restriction,
+    "a wildcard arm in a match on an enum instead of the variants it matches"
+}
+
 #[allow(missing_copy_implementations)]
 pub struct MatchPass;
 
@@ -174,7 +209,8 @@ impl LintPass for MatchPass {
             SINGLE_MATCH_ELSE,
             MATCH_OVERLAPPING_ARM,
             MATCH_WILD_ERR_ARM,
-            MATCH_AS_REF
+            MATCH_AS_REF,
+            WILDCARD_ENUM_MATCH_ARM
         )
     }
 }
@@ -190,6 +226,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MatchPass {
             check_overlapping_arms(cx, ex, arms);
             check_wild_err_arm(cx, ex, arms);
             check_match_as_ref(cx, ex, arms, expr);
+            check_wild_enum_match(cx, ex, arms);
         }
         if let ExprMatch(ref ex, ref arms, _) = expr.node {
             check_match_ref_pats(cx, ex, arms, expr);
@@ -247,6 +284,7 @@ fn report_single_match_single_pattern(cx: &LateContext, ex: &Expr, arms: &[Arm],
             expr_block(cx, &arms[0].body, None, ".."),
             els_str
         ),
+        Applicability::MachineApplicable,
     );
 }
 
@@ -328,7 +366,12 @@ fn check_match_bool(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
                         };
 
                         if let Some(sugg) = sugg {
-                            db.span_suggestion(expr.span, "consider using an if/else expression", sugg);
+                            db.span_suggestion_with_applicability(
+                                expr.span,
+                                "consider using an if/else expression",
+                                sugg,
+                                Applicability::MachineApplicable,
+                            );
                         }
                     }
                 }
@@ -383,6 +426,108 @@ fn check_wild_err_arm(cx: &LateContext, ex: &Expr, arms: &[Arm]) {
     }
 }
 
+fn check_wild_enum_match(cx: &LateContext, ex: &Expr, arms: &[Arm]) {
+    let adt = match walk_ptrs_ty(cx.tables.expr_ty(ex)).sty {
+        ty::TyAdt(adt, _) if adt.is_enum() => adt,
+        _ => return,
+    };
+    // the other crates may add variants to these enums without a breaking change
+    if !adt.did.is_local()
+        && (cx.tcx.has_attr(adt.did, "non_exhaustive")
+            || adt.variants.iter().any(|variant| variant.name.as_str().starts_with("__")))
+    {
+        return;
+    }
+    let wildcard = arms.iter()
+        .filter(|arm| arm.guard.is_none())
+        .flat_map(|arm| arm.pats.iter())
+        .find(|pat| match pat.node {
+            PatKind::Wild | PatKind::Binding(.., None) => true,
+            _ => false,
+        });
+    let wildcard = match wildcard {
+        Some(wildcard) => wildcard,
+        None => return,
+    };
+
+    // the variants matched by the other arms, and the path they are written with, eg. `Color::`
+    let mut matched = Vec::new();
+    let mut prefix = None;
+    for arm in arms {
+        for pat in &arm.pats {
+            if let Some((variant, path, whole)) = variant_pat(cx, pat) {
+                if prefix.is_none() {
+                    prefix = Some(path.rfind("::").map_or_else(String::new, |i| path[..i + 2].to_owned()));
+                }
+                if whole && arm.guard.is_none() {
+                    matched.push(variant);
+                }
+            }
+        }
+    }
+    let prefix = prefix.unwrap_or_else(|| format!("{}::", cx.tcx.item_path_str(adt.did)));
+    let missing = adt.variants
+        .iter()
+        .filter(|variant| !matched.contains(&variant.did))
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return;
+    }
+
+    let names = missing
+        .iter()
+        .map(|variant| format!("`{}{}`", prefix, variant.name))
+        .collect::<Vec<_>>();
+    let msg = match names.split_last() {
+        Some((last, rest)) if !rest.is_empty() => {
+            format!("wildcard arm matching the variants {} and {}", rest.join(", "), last)
+        },
+        _ => format!("wildcard arm matching the variant {}", names[0]),
+    };
+    span_lint_and_then(cx, WILDCARD_ENUM_MATCH_ARM, wildcard.span, &msg, |db| {
+        if let PatKind::Wild = wildcard.node {
+            let arms = missing
+                .iter()
+                .map(|variant| {
+                    let fields = match variant.ctor_kind {
+                        CtorKind::Const => "",
+                        CtorKind::Fn => "(..)",
+                        CtorKind::Fictive => " { .. }",
+                    };
+                    format!("{}{}{}", prefix, variant.name, fields)
+                })
+                .collect::<Vec<_>>();
+            db.span_suggestion_with_applicability(
+                wildcard.span,
+                "list the variants instead",
+                arms.join(" | "),
+                Applicability::MaybeIncorrect,
+            );
+        } else {
+            db.help("list the variants instead, binding them with `@` if needed");
+        }
+    });
+}
+
+/// The variant matched by a pattern, the path it is written with, and whether the pattern matches
+/// every value of the variant.
+fn variant_pat(cx: &LateContext, pat: &Pat) -> Option<(DefId, String, bool)> {
+    let (path, whole) = match pat.node {
+        PatKind::Path(ref path) => (path, true),
+        PatKind::TupleStruct(ref path, ref fields, _) => (path, fields.iter().all(|field| !is_refutable(cx, field))),
+        PatKind::Struct(ref path, ref fields, _) => {
+            (path, fields.iter().all(|field| !is_refutable(cx, &field.node.pat)))
+        },
+        PatKind::Ref(ref pat, _) => return variant_pat(cx, pat),
+        _ => return None,
+    };
+    let variant = match cx.tables.qpath_def(path, pat.hir_id) {
+        Def::Variant(id) | Def::VariantCtor(id, _) => id,
+        _ => return None,
+    };
+    Some((variant, print::to_string(print::NO_ANN, |s| s.print_qpath(path, false)), whole))
+}
+
 // If the block contains only a `panic!` macro (as expression or statement)
 fn is_panic_block(block: &Block) -> bool {
     match (&block.expr, block.stmts.len(), block.stmts.first()) {
@@ -422,7 +567,7 @@ fn check_match_ref_pats(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr)
         }));
 
         span_lint_and_then(cx, MATCH_REF_PATS, expr.span, title, |db| {
-            multispan_sugg(db, msg.to_owned(), suggs);
+            multispan_sugg(db, msg.to_owned(), suggs, Applicability::MachineApplicable);
         });
     }
 }
@@ -446,7 +591,8 @@ fn check_match_as_ref(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
                 expr.span,
                 &format!("use {}() instead", suggestion),
                 "try this",
-                format!("{}.{}()", snippet(cx, ex.span, "_"), suggestion)
+                format!("{}.{}()", snippet(cx, ex.span, "_"), suggestion),
+                Applicability::MachineApplicable
             )
         }
     }
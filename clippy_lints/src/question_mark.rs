@@ -4,28 +4,28 @@ use rustc::hir::def::Def;
 use utils::sugg::Sugg;
 use syntax::ptr::P;
 
-use utils::{match_def_path, match_type, span_lint_and_then};
+use utils::{match_def_path, match_type, span_lint_and_then, Applicability, DiagnosticBuilderApplicabilityExt};
 use utils::paths::*;
 
-/// **What it does:** Checks for expressions that could be replaced by the question mark operator
-///
-/// **Why is this bad?** Question mark usage is more idiomatic
-///
-/// **Known problems:** None
-///
-/// **Example:**
-/// ```rust
-/// if option.is_none() {
-///     return None;
-/// }
-/// ```
-///
-/// Could be written:
-///
-/// ```rust
-/// option?;
-/// ```
-declare_clippy_lint!{
+declare_clippy_lint! {
+    /// **What it does:** Checks for expressions that could be replaced by the question mark operator
+    ///
+    /// **Why is this bad?** Question mark usage is more idiomatic
+    ///
+    /// **Known problems:** None
+    ///
+    /// **Example:**
+    /// ```rust
+    /// if option.is_none() {
+    ///     return None;
+    /// }
+    /// ```
+    ///
+    /// Could be written:
+    ///
+    /// ```rust
+    /// option?;
+    /// ```
     pub QUESTION_MARK,
     style,
     "checks for expressions that could be replaced by the question mark operator"
@@ -68,10 +68,11 @@ impl QuestionMarkPass {
                     |db| {
                         let receiver_str = &Sugg::hir(cx, subject, "..");
 
-                        db.span_suggestion(
+                        db.span_suggestion_with_applicability(
                             expr.span,
                             "replace_it_with",
                             format!("{}?;", receiver_str),
+                            Applicability::MachineApplicable,
                         );
                     }
                 )
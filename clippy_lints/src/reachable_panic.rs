@@ -0,0 +1,261 @@
+use consts::{constant, Constant};
+use doc;
+use rustc::hir::*;
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use rustc::ty;
+use std::collections::{HashMap, VecDeque};
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+use utils::{in_macro, is_expn_of, item_header_span, match_def_path, match_type, opt_def_id, paths, resolve_node,
+            span_lint_and_then, walk_ptrs_ty};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for public functions from which a panic is
+    /// reachable, through the calls to the other functions of the crate, when
+    /// their documentation has no `# Panics` section. The panics are `panic!` and
+    /// the macros calling it like `unreachable!` or `assert!`, `unwrap` and
+    /// `expect` on `Option` and `Result`, and indexing arrays, slices, vectors
+    /// and strings. The path from the function to the panic is shown.
+    ///
+    /// **Why is this bad?** The callers of the function cannot know they must
+    /// avoid a panic, this matters for libraries promising not to panic.
+    ///
+    /// **Known problems:** Calls through trait objects, generic parameters and
+    /// function pointers are not followed, and panics in other crates are not
+    /// found. A panic is reported even if it cannot happen in practice, like
+    /// indexing with an index checked beforehand.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// pub fn first(v: &[u32]) -> u32 {
+    ///     get(v, 0)
+    /// }
+    ///
+    /// fn get(v: &[u32], i: usize) -> u32 {
+    ///     v[i]
+    /// }
+    /// ```
+    pub UNDOCUMENTED_REACHABLE_PANIC,
+    restriction,
+    "public function from which a panic is reachable without a `# Panics` section"
+}
+
+/// What a function does, for the lint.
+#[derive(Default)]
+struct FnInfo {
+    /// The first panic of the function, and what panics.
+    panic: Option<(Span, String)>,
+    /// The calls to the functions of the crate.
+    calls: Vec<(DefId, Span)>,
+}
+
+#[derive(Default)]
+pub struct ReachablePanic {
+    fns: HashMap<DefId, FnInfo>,
+    /// The public functions without a `# Panics` section, with the span to lint.
+    public: Vec<(DefId, Span)>,
+}
+
+impl LintPass for ReachablePanic {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNDOCUMENTED_REACHABLE_PANIC)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ReachablePanic {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        // the closures are part of the function defining them
+        if let FnKind::Closure(_) = kind {
+            return;
+        }
+        let def_id = cx.tcx.hir.local_def_id(node_id);
+        let mut visitor = PanicVisitor {
+            cx,
+            indexing: true,
+            info: FnInfo::default(),
+        };
+        visitor.visit_expr(&body.value);
+        self.fns.insert(def_id, visitor.info);
+
+        if cx.access_levels.is_exported(node_id) && !in_macro(span)
+            && !doc::has_section(cx.tcx.hir.attrs(node_id), "Panics")
+        {
+            self.public.push((def_id, item_header_span(cx, span)));
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for &(def_id, span) in &self.public {
+            let chain = match self.panic_chain(def_id) {
+                Some(chain) => chain,
+                None => continue,
+            };
+            let name = cx.tcx.item_path_str(def_id);
+            span_lint_and_then(
+                cx,
+                UNDOCUMENTED_REACHABLE_PANIC,
+                span,
+                &format!("`{}` may panic but its documentation has no `# Panics` section", name),
+                |db| {
+                    let mut caller = def_id;
+                    for &(callee, call) in &chain {
+                        db.span_note(
+                            call,
+                            &format!(
+                                "`{}` calls `{}`",
+                                cx.tcx.item_path_str(caller),
+                                cx.tcx.item_path_str(callee)
+                            ),
+                        );
+                        caller = callee;
+                    }
+                    if let Some((panic, ref what)) = self.fns[&caller].panic {
+                        db.span_note(
+                            panic,
+                            &format!("`{}` panics here with {}", cx.tcx.item_path_str(caller), what),
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
+
+impl ReachablePanic {
+    /// The shortest chain of calls from `start` to a function that panics: the callees and the
+    /// spans of the calls. The chain is empty if `start` panics itself.
+    fn panic_chain(&self, start: DefId) -> Option<Vec<(DefId, Span)>> {
+        // the call reaching each function first
+        let mut reached: HashMap<DefId, Option<(DefId, Span)>> = HashMap::new();
+        let mut queue = VecDeque::new();
+        reached.insert(start, None);
+        queue.push_back(start);
+        while let Some(def_id) = queue.pop_front() {
+            let info = match self.fns.get(&def_id) {
+                Some(info) => info,
+                None => continue,
+            };
+            if info.panic.is_some() {
+                let mut chain = Vec::new();
+                let mut current = def_id;
+                while let Some((caller, call)) = reached[&current] {
+                    chain.push((current, call));
+                    current = caller;
+                }
+                chain.reverse();
+                return Some(chain);
+            }
+            for &(callee, call) in &info.calls {
+                if !reached.contains_key(&callee) {
+                    reached.insert(callee, Some((def_id, call)));
+                    queue.push_back(callee);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The first `panic!`, `unwrap` or `expect` of a function, without following its calls. Indexing is
+/// not included as it would be found in most functions.
+pub fn visible_panic<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>, body: &'tcx Body) -> Option<(Span, String)> {
+    let mut visitor = PanicVisitor {
+        cx,
+        indexing: false,
+        info: FnInfo::default(),
+    };
+    visitor.visit_expr(&body.value);
+    visitor.info.panic
+}
+
+struct PanicVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    /// Whether indexing is a panic.
+    indexing: bool,
+    info: FnInfo,
+}
+
+impl<'a, 'tcx> PanicVisitor<'a, 'tcx> {
+    fn panic(&mut self, span: Span, what: String) {
+        if self.info.panic.is_none() {
+            // point at the macro call for `panic!` and at the code using a macro
+            self.info.panic = Some((span.source_callsite(), what));
+        }
+    }
+
+    fn call(&mut self, def_id: DefId, span: Span) {
+        if def_id.is_local() {
+            self.info.calls.push((def_id, span.source_callsite()));
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for PanicVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprCall(ref fun, _) => if let ExprPath(ref qpath) = fun.node {
+                if let Some(def_id) = opt_def_id(resolve_node(self.cx, qpath, fun.hir_id)) {
+                    if match_def_path(self.cx.tcx, def_id, &paths::BEGIN_PANIC)
+                        || match_def_path(self.cx.tcx, def_id, &paths::BEGIN_PANIC_FMT)
+                    {
+                        self.panic(expr.span, format!("`{}!`", panic_macro(expr.span)));
+                    } else {
+                        self.call(def_id, expr.span);
+                    }
+                }
+            },
+            ExprMethodCall(ref path, _, ref args) => {
+                let recv_ty = walk_ptrs_ty(self.cx.tables.expr_ty(&args[0]));
+                if (path.name == "unwrap" || path.name == "expect")
+                    && (match_type(self.cx, recv_ty, &paths::OPTION) || match_type(self.cx, recv_ty, &paths::RESULT))
+                {
+                    self.panic(expr.span, format!("`{}`", path.name));
+                } else if let Some(def) = self.cx.tables.type_dependent_defs().get(expr.hir_id) {
+                    self.call(def.def_id(), expr.span);
+                }
+            },
+            ExprIndex(ref base, ref index) => if self.indexing && index_may_panic(self.cx, base, index) {
+                self.panic(expr.span, "indexing".to_owned());
+            },
+            _ => (),
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
+
+/// The macro a call to `begin_panic` comes from.
+fn panic_macro(span: Span) -> &'static str {
+    const MACROS: &[&str] = &["unreachable", "unimplemented", "assert_eq", "assert_ne", "assert"];
+    MACROS
+        .iter()
+        .find(|name| is_expn_of(span, name).is_some())
+        .map_or("panic", |name| *name)
+}
+
+/// Returns true if indexing `base` with `index` may panic: arrays, except with a constant index in
+/// bounds which the compiler checks, slices, vectors and strings.
+fn index_may_panic(cx: &LateContext, base: &Expr, index: &Expr) -> bool {
+    let ty = walk_ptrs_ty(cx.tables.expr_ty(base));
+    match ty.sty {
+        ty::TyArray(_, size) => match constant(cx, index) {
+            Some((Constant::Int(index), _)) => size.val.to_raw_bits().map_or(true, |size| index >= size),
+            _ => true,
+        },
+        ty::TySlice(_) | ty::TyStr => true,
+        _ => match_type(cx, ty, &paths::VEC),
+    }
+}
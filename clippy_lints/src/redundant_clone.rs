@@ -0,0 +1,141 @@
+use rustc::hir::intravisit::FnKind;
+use rustc::hir::{Body, FnDecl};
+use rustc::lint::*;
+use rustc::mir::{BasicBlock, Local, Location, Mir, Operand, Place, Rvalue, StatementKind, TerminatorKind};
+use rustc::ty;
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+use utils::{in_macro, is_copy, match_def_path, paths, snippet_opt, span_lint_and_sugg, Applicability};
+use utils::mir::{borrows_of, fn_mir, is_used_from};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `.clone()` on an owned value which is not
+    /// used anymore after the clone.
+    ///
+    /// **Why is this bad?** The value could be moved instead, cloning it costs
+    /// an allocation for types like `String` or `Vec`.
+    ///
+    /// **Known problems:** Only local variables and temporaries are checked, not
+    /// their fields. A value borrowed before the clone is not linted if the borrow
+    /// could still be used afterwards.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let name = String::from("clippy");
+    /// let greeting = format_greeting(name.clone());
+    /// ```
+    pub REDUNDANT_CLONE,
+    perf,
+    "`clone()` of an owned value which is not used afterwards"
+}
+
+#[derive(Copy, Clone)]
+pub struct RedundantClone;
+
+impl LintPass for RedundantClone {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_CLONE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RedundantClone {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        _: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        _: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if in_macro(span) {
+            return;
+        }
+        let mir = match fn_mir(cx, node_id) {
+            Some(mir) => mir,
+            None => return,
+        };
+        let mir = &*mir;
+
+        for (bb, data) in mir.basic_blocks().iter_enumerated() {
+            let terminator = data.terminator();
+            let (func, args, dest, next) = match terminator.kind {
+                TerminatorKind::Call {
+                    ref func,
+                    ref args,
+                    destination: Some((Place::Local(dest), next)),
+                    ..
+                } => (func, args, dest, next),
+                _ => continue,
+            };
+            let span = terminator.source_info.span;
+            if in_macro(span) || !is_clone(cx, mir, func) {
+                continue;
+            }
+
+            // `x.clone()` is `tmp = &x; dest = Clone::clone(move tmp)`
+            let arg = match args.get(0) {
+                Some(&Operand::Move(Place::Local(arg))) | Some(&Operand::Copy(Place::Local(arg))) => arg,
+                _ => continue,
+            };
+            let (cloned, borrow) = match borrowed_local(mir, bb, arg) {
+                Some(borrowed) => borrowed,
+                None => continue,
+            };
+            let ty = mir.local_decls[cloned].ty;
+            if is_copy(cx, ty) || mir.local_decls[dest].ty != ty {
+                continue;
+            }
+
+            // the other borrows of the value must not be used after the clone either, and the
+            // borrows stored in variables last until the end of their scope
+            let mut locals = borrows_of(mir, cloned, Some(borrow));
+            if locals.iter().any(|&local| mir.local_decls[local].name.is_some()) {
+                continue;
+            }
+            locals.insert(cloned);
+            if is_used_from(mir, next, &locals) {
+                continue;
+            }
+
+            if let Some(snippet) = snippet_opt(cx, span) {
+                if snippet.ends_with(".clone()") {
+                    span_lint_and_sugg(
+                        cx,
+                        REDUNDANT_CLONE,
+                        span,
+                        "redundant clone",
+                        "remove the `.clone()`",
+                        snippet[..snippet.len() - ".clone()".len()].trim_right().to_owned(),
+                        Applicability::MachineApplicable,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns true if `func` is `Clone::clone`.
+fn is_clone<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, mir: &Mir<'tcx>, func: &Operand<'tcx>) -> bool {
+    match func.ty(mir, cx.tcx).sty {
+        ty::TyFnDef(def_id, _) => match_def_path(cx.tcx, def_id, &paths::CLONE),
+        _ => false,
+    }
+}
+
+/// The local `arg` borrows and the location of the borrow, if it is assigned `&local` in the block
+/// `bb`.
+fn borrowed_local(mir: &Mir, bb: BasicBlock, arg: Local) -> Option<(Local, Location)> {
+    for (index, stmt) in mir[bb].statements.iter().enumerate().rev() {
+        if let StatementKind::Assign(Place::Local(local), Rvalue::Ref(_, _, Place::Local(borrowed))) = stmt.kind {
+            if local == arg {
+                let location = Location {
+                    block: bb,
+                    statement_index: index,
+                };
+                return Some((borrowed, location));
+            }
+        }
+    }
+    None
+}
@@ -1,68 +1,117 @@
 use regex_syntax;
 use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::map;
 use rustc::lint::*;
+use std::cmp;
 use std::collections::HashSet;
 use syntax::ast::{LitKind, NodeId, StrStyle};
 use syntax::codemap::{BytePos, Span};
-use utils::{is_expn_of, match_def_path, match_type, opt_def_id, paths, span_help_and_lint, span_lint};
+use utils::{is_expn_of, match_def_path, match_type, opt_def_id, paths, span_help_and_lint, span_lint, walk_ptrs_ty};
+use utils::higher;
 use consts::{constant, Constant};
 
-/// **What it does:** Checks [regex](https://crates.io/crates/regex) creation
-/// (with `Regex::new`,`RegexBuilder::new` or `RegexSet::new`) for correct
-/// regex syntax.
-///
-/// **Why is this bad?** This will lead to a runtime panic.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// Regex::new("|")
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks [regex](https://crates.io/crates/regex) creation
+    /// (with `Regex::new`, `RegexBuilder::new`, `RegexSet::new` or
+    /// `RegexSetBuilder::new`) for correct regex syntax. The options set on a
+    /// builder, like `case_insensitive` or `ignore_whitespace`, are taken into
+    /// account, and each pattern of a set is checked.
+    ///
+    /// **Why is this bad?** This will lead to a runtime panic.
+    ///
+    /// **Known problems:** Builders are only followed when their methods are
+    /// chained to `new` and the options are constants.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// Regex::new("|")
+    /// ```
     pub INVALID_REGEX,
     correctness,
     "invalid regular expressions"
 }
 
-/// **What it does:** Checks for trivial [regex](https://crates.io/crates/regex)
-/// creation (with `Regex::new`, `RegexBuilder::new` or `RegexSet::new`).
-///
-/// **Why is this bad?** Matching the regex can likely be replaced by `==` or
-/// `str::starts_with`, `str::ends_with` or `std::contains` or other `str`
-/// methods.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// Regex::new("^foobar")
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for trivial [regex](https://crates.io/crates/regex)
+    /// creation (with `Regex::new`, `RegexBuilder::new` or `RegexSet::new`).
+    ///
+    /// **Why is this bad?** Matching the regex can likely be replaced by `==` or
+    /// `str::starts_with`, `str::ends_with` or `std::contains` or other `str`
+    /// methods.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// Regex::new("^foobar")
+    /// ```
     pub TRIVIAL_REGEX,
     style,
     "trivial regular expressions"
 }
 
-/// **What it does:** Checks for usage of `regex!(_)` which (as of now) is
-/// usually slower than `Regex::new(_)` unless called in a loop (which is a bad
-/// idea anyway).
-///
-/// **Why is this bad?** Performance, at least for now. The macro version is
-/// likely to catch up long-term, but for now the dynamic version is faster.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// regex!("foo|bar")
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for usage of `regex!(_)` which (as of now) is
+    /// usually slower than `Regex::new(_)` unless called in a loop (which is a bad
+    /// idea anyway).
+    ///
+    /// **Why is this bad?** Performance, at least for now. The macro version is
+    /// likely to catch up long-term, but for now the dynamic version is faster.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// regex!("foo|bar")
+    /// ```
     pub REGEX_MACRO,
     style,
     "use of `regex!(_)` instead of `Regex::new(_)`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks for regexes with a constant pattern compiled
+    /// inside a loop.
+    ///
+    /// **Why is this bad?** Compiling a regex is expensive, the same regex is
+    /// compiled again at each iteration.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// for line in text.lines() {
+    ///     let re = Regex::new(r"^\d+$").unwrap();
+    ///     if re.is_match(line) { .. }
+    /// }
+    /// ```
+    pub REGEX_CREATION_IN_LOOPS,
+    perf,
+    "compiling a regex with a constant pattern inside a loop"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for capture groups accessed by an index larger
+    /// than the number of groups of the regex, with `caps[N]` or `caps.get(N)`.
+    ///
+    /// **Why is this bad?** Indexing panics and `get` always returns `None`.
+    ///
+    /// **Known problems:** The regex is only found when it is created with a
+    /// constant pattern in the same function and bound to a local variable.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let re = Regex::new(r"(\d+)-(\d+)").unwrap();
+    /// let caps = re.captures(text).unwrap();
+    /// let third = &caps[3];
+    /// ```
+    pub REGEX_CAPTURE_INDEX_OUT_OF_BOUNDS,
+    correctness,
+    "accessing a capture group the regex does not have"
+}
+
 #[derive(Clone, Default)]
 pub struct Pass {
     spans: HashSet<Span>,
@@ -71,7 +120,13 @@ pub struct Pass {
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(INVALID_REGEX, REGEX_MACRO, TRIVIAL_REGEX)
+        lint_array!(
+            INVALID_REGEX,
+            REGEX_MACRO,
+            TRIVIAL_REGEX,
+            REGEX_CREATION_IN_LOOPS,
+            REGEX_CAPTURE_INDEX_OUT_OF_BOUNDS
+        )
     }
 }
 
@@ -112,20 +167,341 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
             if let ExprPath(ref qpath) = fun.node;
             if args.len() == 1;
             if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+            if let Some(creation) = creation(cx, def_id);
             then {
-                if match_def_path(cx.tcx, def_id, &paths::REGEX_NEW) ||
-                   match_def_path(cx.tcx, def_id, &paths::REGEX_BUILDER_NEW) {
-                    check_regex(cx, &args[0], true);
-                } else if match_def_path(cx.tcx, def_id, &paths::REGEX_BYTES_NEW) ||
-                   match_def_path(cx.tcx, def_id, &paths::REGEX_BYTES_BUILDER_NEW) {
-                    check_regex(cx, &args[0], false);
-                } else if match_def_path(cx.tcx, def_id, &paths::REGEX_SET_NEW) {
-                    check_set(cx, &args[0], true);
-                } else if match_def_path(cx.tcx, def_id, &paths::REGEX_BYTES_SET_NEW) {
-                    check_set(cx, &args[0], false);
+                let flags = if creation.builder {
+                    builder_flags(cx, expr.id)
+                } else {
+                    Some(Flags::default())
+                };
+                // the pattern cannot be checked if an option is not a constant
+                if let Some(flags) = flags {
+                    if creation.set {
+                        check_set(cx, &args[0], creation.utf8, &flags);
+                    } else {
+                        check_regex(cx, &args[0], creation.utf8, &flags);
+                    }
+                }
+                if is_constant_pattern(cx, &args[0], creation.set) && in_loop(cx, expr.id) {
+                    span_help_and_lint(
+                        cx,
+                        REGEX_CREATION_IN_LOOPS,
+                        expr.span,
+                        "compiling a regex in a loop",
+                        "move the regex out of the loop, eg. into a `lazy_static!`",
+                    );
                 }
             }
         }
+
+        match expr.node {
+            ExprIndex(ref caps, ref index) => check_capture_index(cx, caps, index, expr.span, true),
+            ExprMethodCall(ref segment, _, ref args) if segment.name == "get" && args.len() == 2 => {
+                check_capture_index(cx, &args[0], &args[1], expr.span, false)
+            },
+            _ => (),
+        }
+    }
+}
+
+/// How a function creates a regex.
+#[derive(Clone, Copy)]
+struct Creation {
+    /// `false` for the regexes of `regex::bytes`.
+    utf8: bool,
+    /// The argument is a list of patterns.
+    set: bool,
+    /// `RegexBuilder::new` or `RegexSetBuilder::new`.
+    builder: bool,
+}
+
+/// Returns how the function `def_id` creates a regex, if it does.
+fn creation(cx: &LateContext, def_id: DefId) -> Option<Creation> {
+    let creations: [(&[&str], Creation); 8] = [
+        (&paths::REGEX_NEW, Creation { utf8: true, set: false, builder: false }),
+        (&paths::REGEX_BUILDER_NEW, Creation { utf8: true, set: false, builder: true }),
+        (&paths::REGEX_BYTES_NEW, Creation { utf8: false, set: false, builder: false }),
+        (&paths::REGEX_BYTES_BUILDER_NEW, Creation { utf8: false, set: false, builder: true }),
+        (&paths::REGEX_SET_NEW, Creation { utf8: true, set: true, builder: false }),
+        (&paths::REGEX_SET_BUILDER_NEW, Creation { utf8: true, set: true, builder: true }),
+        (&paths::REGEX_BYTES_SET_NEW, Creation { utf8: false, set: true, builder: false }),
+        (&paths::REGEX_BYTES_SET_BUILDER_NEW, Creation { utf8: false, set: true, builder: true }),
+    ];
+    creations
+        .iter()
+        .find(|&&(path, _)| match_def_path(cx.tcx, def_id, path))
+        .map(|&(_, creation)| creation)
+}
+
+/// The options of `RegexBuilder` and `RegexSetBuilder` that change how the patterns are parsed.
+#[derive(Clone, Copy, Default)]
+struct Flags {
+    case_insensitive: bool,
+    multi_line: bool,
+    dot_matches_new_line: bool,
+    swap_greed: bool,
+    ignore_whitespace: bool,
+    octal: bool,
+    unicode: Option<bool>,
+    nest_limit: Option<u32>,
+}
+
+impl Flags {
+    /// Apply the builder method `method` called with `args`. Returns false if the value of an
+    /// option is not a constant.
+    fn apply<'a, 'tcx>(&mut self, cx: &LateContext<'a, 'tcx>, method: &str, args: &'tcx [Expr]) -> bool {
+        let value = args.get(1).and_then(|arg| constant(cx, arg)).map(|(value, _)| value);
+        let flag = match method {
+            "case_insensitive" => &mut self.case_insensitive,
+            "multi_line" => &mut self.multi_line,
+            "dot_matches_new_line" => &mut self.dot_matches_new_line,
+            "swap_greed" => &mut self.swap_greed,
+            "ignore_whitespace" => &mut self.ignore_whitespace,
+            "octal" => &mut self.octal,
+            "unicode" => {
+                return match value {
+                    Some(Constant::Bool(unicode)) => {
+                        self.unicode = Some(unicode);
+                        true
+                    },
+                    _ => false,
+                }
+            },
+            "nest_limit" => {
+                return match value {
+                    Some(Constant::Int(limit)) => {
+                        self.nest_limit = Some(limit as u32);
+                        true
+                    },
+                    _ => false,
+                }
+            },
+            // `size_limit`, `build`...
+            _ => return true,
+        };
+        match value {
+            Some(Constant::Bool(value)) => {
+                *flag = value;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    fn parser(&self, utf8: bool) -> regex_syntax::Parser {
+        let mut builder = regex_syntax::ParserBuilder::new();
+        builder
+            .unicode(self.unicode.unwrap_or(utf8))
+            .allow_invalid_utf8(!utf8)
+            .case_insensitive(self.case_insensitive)
+            .multi_line(self.multi_line)
+            .dot_matches_new_line(self.dot_matches_new_line)
+            .swap_greed(self.swap_greed)
+            .ignore_whitespace(self.ignore_whitespace)
+            .octal(self.octal);
+        if let Some(limit) = self.nest_limit {
+            builder.nest_limit(limit);
+        }
+        builder.build()
+    }
+}
+
+/// Collect the options set by the methods chained to the builder created by the call `id`.
+fn builder_flags(cx: &LateContext, id: NodeId) -> Option<Flags> {
+    let mut flags = Flags::default();
+    let mut id = id;
+    loop {
+        let parent = cx.tcx.hir.get_parent_node(id);
+        match cx.tcx.hir.find(parent) {
+            Some(map::NodeExpr(expr)) => match expr.node {
+                ExprMethodCall(ref segment, _, ref args) if args[0].id == id => {
+                    if !flags.apply(cx, &segment.name.as_str(), args) {
+                        return None;
+                    }
+                },
+                _ => return Some(flags),
+            },
+            _ => return Some(flags),
+        }
+        id = parent;
+    }
+}
+
+/// Find the regex created by `expr`, like `Regex::new(..)` or `RegexBuilder::new(..).build()`.
+/// Returns the pattern, how it is created and the options of the builder.
+fn find_creation<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> Option<(&'tcx Expr, Creation, Flags)> {
+    let mut calls = Vec::new();
+    let mut expr = expr;
+    while let ExprMethodCall(ref segment, _, ref args) = expr.node {
+        calls.push((segment.name, args));
+        expr = &args[0];
+    }
+    if_chain! {
+        if let ExprCall(ref fun, ref args) = expr.node;
+        if let ExprPath(ref qpath) = fun.node;
+        if args.len() == 1;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+        if let Some(creation) = creation(cx, def_id);
+        then {
+            let mut flags = Flags::default();
+            for &(name, args) in calls.iter().rev() {
+                if !flags.apply(cx, &name.as_str(), args) {
+                    return None;
+                }
+            }
+            return Some((&args[0], creation, flags));
+        }
+    }
+    None
+}
+
+fn is_constant_pattern<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr, set: bool) -> bool {
+    if set {
+        set_patterns(cx, expr).map_or(false, |patterns| patterns.iter().all(|p| const_str(cx, p).is_some()))
+    } else {
+        const_str(cx, expr).is_some()
+    }
+}
+
+/// Returns true if the expression `id` is evaluated at each iteration of a loop of its function.
+fn in_loop(cx: &LateContext, id: NodeId) -> bool {
+    let mut id = id;
+    loop {
+        let parent = cx.tcx.hir.get_parent_node(id);
+        if parent == id {
+            return false;
+        }
+        match cx.tcx.hir.find(parent) {
+            Some(map::NodeExpr(expr)) => match expr.node {
+                ExprLoop(..) | ExprWhile(..) => return true,
+                // the closure may be called only once
+                ExprClosure(..) => return false,
+                _ => (),
+            },
+            Some(map::NodeItem(_)) | Some(map::NodeImplItem(_)) | Some(map::NodeTraitItem(_)) | None => return false,
+            _ => (),
+        }
+        id = parent;
+    }
+}
+
+/// Check `caps[index]` or `caps.get(index)` where `caps` are the captures of a known regex.
+fn check_capture_index<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    caps: &'tcx Expr,
+    index: &'tcx Expr,
+    span: Span,
+    panics: bool,
+) {
+    let ty = walk_ptrs_ty(cx.tables.expr_ty(caps));
+    if !match_type(cx, ty, &paths::REGEX_CAPTURES) && !match_type(cx, ty, &paths::REGEX_BYTES_CAPTURES) {
+        return;
+    }
+    if_chain! {
+        if let Some((Constant::Int(index), _)) = constant(cx, index);
+        if let ExprMethodCall(ref segment, _, ref args) = origin(cx, caps).node;
+        if segment.name == "captures" || segment.name == "captures_iter";
+        if let Some((pattern, creation, flags)) = find_creation(cx, origin(cx, &args[0]));
+        if !creation.set;
+        if let Some(pattern) = const_str(cx, pattern);
+        if let Ok(hir) = flags.parser(creation.utf8).parse(&pattern);
+        let len = u128::from(max_group(&hir)) + 1;
+        if index >= len;
+        then {
+            let consequence = if panics { "this panics" } else { "this is always `None`" };
+            span_lint(
+                cx,
+                REGEX_CAPTURE_INDEX_OUT_OF_BOUNDS,
+                span,
+                &format!(
+                    "the regex only has the capture groups 0 to {}, {}",
+                    len - 1,
+                    consequence
+                ),
+            );
+        }
+    }
+}
+
+/// Follow `expr` to where its value comes from: through local variables, `unwrap` and `expect`.
+fn origin<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> &'tcx Expr {
+    let mut expr = expr;
+    // bounded in case of a cycle we do not know about
+    for _ in 0..16 {
+        expr = match expr.node {
+            ExprAddrOf(_, ref inner) => inner,
+            ExprMethodCall(ref segment, _, ref args) if segment.name == "unwrap" || segment.name == "expect" => {
+                &args[0]
+            },
+            ExprPath(ref qpath) => match cx.tables.qpath_def(qpath, expr.hir_id) {
+                Def::Local(id) => match binding_value(cx, id) {
+                    Some(value) => value,
+                    None => return expr,
+                },
+                _ => return expr,
+            },
+            _ => return expr,
+        };
+    }
+    expr
+}
+
+/// The value a local binding is bound to: the initializer of its `let`, the matched expression
+/// of its `match` or `if let`, or the iterator of its `for` loop.
+fn binding_value<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, id: NodeId) -> Option<&'tcx Expr> {
+    let mut id = id;
+    loop {
+        let parent = cx.tcx.hir.get_parent_node(id);
+        if parent == id {
+            return None;
+        }
+        match cx.tcx.hir.find(parent) {
+            Some(map::NodeLocal(local)) => {
+                return for_loop_iterator(cx, local).or_else(|| local.init.as_ref().map(|init| &**init))
+            },
+            Some(map::NodeExpr(expr)) => {
+                return match expr.node {
+                    ExprMatch(ref matched, _, _) => Some(matched),
+                    _ => None,
+                }
+            },
+            Some(map::NodePat(_)) | Some(map::NodeBinding(_)) => id = parent,
+            _ => return None,
+        }
+    }
+}
+
+/// If `local` is the `let pat = __next;` of a desugared `for pat in iter` loop, returns `iter`.
+fn for_loop_iterator<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, local: &'tcx Local) -> Option<&'tcx Expr> {
+    let mut id = local.id;
+    // the `let` is in the block of a `loop` in the desugared `match`
+    for _ in 0..4 {
+        id = cx.tcx.hir.get_parent_node(id);
+        if let Some(map::NodeExpr(expr)) = cx.tcx.hir.find(id) {
+            if let Some((pat, iter, _)) = higher::for_loop(expr) {
+                return if pat.id == local.pat.id { Some(iter) } else { None };
+            }
+        }
+    }
+    None
+}
+
+/// The largest capture group index of a regex.
+fn max_group(hir: &regex_syntax::hir::Hir) -> u32 {
+    use regex_syntax::hir::GroupKind;
+    use regex_syntax::hir::HirKind::*;
+
+    match *hir.kind() {
+        Group(ref group) => {
+            let index = match group.kind {
+                GroupKind::CaptureIndex(index) | GroupKind::CaptureName { index, .. } => index,
+                GroupKind::NonCapturing => 0,
+            };
+            cmp::max(index, max_group(&group.hir))
+        },
+        Repetition(ref repetition) => max_group(&repetition.hir),
+        Concat(ref hirs) | Alternation(ref hirs) => hirs.iter().map(max_group).max().unwrap_or(0),
+        _ => 0,
     }
 }
 
@@ -174,23 +550,31 @@ fn is_trivial_regex(s: &regex_syntax::hir::Hir) -> Option<&'static str> {
     }
 }
 
-fn check_set<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr, utf8: bool) {
-    if_chain! {
-        if let ExprAddrOf(_, ref expr) = expr.node;
-        if let ExprArray(ref exprs) = expr.node;
-        then {
-            for expr in exprs {
-                check_regex(cx, expr, utf8);
-            }
+/// The patterns of a set: `&[..]`, `[..]` or `vec![..]`.
+fn set_patterns<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> Option<&'tcx [Expr]> {
+    let expr = match expr.node {
+        ExprAddrOf(_, ref inner) => inner,
+        _ => expr,
+    };
+    match expr.node {
+        ExprArray(ref exprs) => Some(exprs),
+        _ => match higher::vec_macro(cx, expr) {
+            Some(higher::VecArgs::Vec(exprs)) => Some(exprs),
+            _ => None,
+        },
+    }
+}
+
+fn check_set<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr, utf8: bool, flags: &Flags) {
+    if let Some(exprs) = set_patterns(cx, expr) {
+        for expr in exprs {
+            check_regex(cx, expr, utf8, flags);
         }
     }
 }
 
-fn check_regex<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr, utf8: bool) {
-    let mut parser = regex_syntax::ParserBuilder::new()
-        .unicode(utf8)
-        .allow_invalid_utf8(!utf8)
-        .build();
+fn check_regex<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr, utf8: bool, flags: &Flags) {
+    let mut parser = flags.parser(utf8);
 
     if let ExprLit(ref lit) = expr.node {
         if let LitKind::Str(ref r, style) = lit.node {
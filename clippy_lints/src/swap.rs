@@ -3,38 +3,39 @@ use rustc::lint::*;
 use rustc::ty;
 use utils::{differing_macro_contexts, match_type, paths, snippet, span_lint_and_then, walk_ptrs_ty, SpanlessEq};
 use utils::sugg::Sugg;
+use utils::{Applicability, DiagnosticBuilderApplicabilityExt};
 
-/// **What it does:** Checks for manual swapping.
-///
-/// **Why is this bad?** The `std::mem::swap` function exposes the intent better
-/// without deinitializing or copying either variable.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust,ignore
-/// let t = b;
-/// b = a;
-/// a = t;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for manual swapping.
+    ///
+    /// **Why is this bad?** The `std::mem::swap` function exposes the intent better
+    /// without deinitializing or copying either variable.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// let t = b;
+    /// b = a;
+    /// a = t;
+    /// ```
     pub MANUAL_SWAP,
     complexity,
     "manual swap of two variables"
 }
 
-/// **What it does:** Checks for `foo = bar; bar = foo` sequences.
-///
-/// **Why is this bad?** This looks like a failed attempt to swap.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust,ignore
-/// a = b;
-/// b = a;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for `foo = bar; bar = foo` sequences.
+    ///
+    /// **Why is this bad?** This looks like a failed attempt to swap.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// a = b;
+    /// b = a;
+    /// ```
     pub ALMOST_SWAPPED,
     correctness,
     "`foo = bar; bar = foo` sequence"
@@ -129,7 +130,12 @@ fn check_manual_swap(cx: &LateContext, block: &Block) {
                                    &format!("this looks like you are swapping{} manually", what),
                                    |db| {
                                        if !sugg.is_empty() {
-                                           db.span_suggestion(span, "try", sugg);
+                                           db.span_suggestion_with_applicability(
+                                               span,
+                                               "try",
+                                               sugg,
+                                               Applicability::MachineApplicable,
+                                           );
 
                                            if replace {
                                                db.note("or maybe you should use `std::mem::replace`?");
@@ -173,8 +179,12 @@ fn check_suspicious_swap(cx: &LateContext, block: &Block) {
                                    &format!("this looks like you are trying to swap{}", what),
                                    |db| {
                                        if !what.is_empty() {
-                                           db.span_suggestion(span, "try",
-                                                              format!("std::mem::swap({}, {})", lhs, rhs));
+                                           db.span_suggestion_with_applicability(
+                                               span,
+                                               "try",
+                                               format!("std::mem::swap({}, {})", lhs, rhs),
+                                               Applicability::MaybeIncorrect,
+                                           );
                                            db.note("or maybe you should use `std::mem::replace`?");
                                        }
                                    });
@@ -5,182 +5,182 @@ use rustc::ty::layout::LayoutOf;
 use std::borrow::Cow;
 use syntax::ast;
 use utils::{last_path_segment, match_def_path, paths, snippet, span_lint, span_lint_and_then};
-use utils::{opt_def_id, sugg};
+use utils::{opt_def_id, sugg, Applicability, DiagnosticBuilderApplicabilityExt};
 
-/// **What it does:** Checks for transmutes that can't ever be correct on any
-/// architecture.
-///
-/// **Why is this bad?** It's basically guaranteed to be undefined behaviour.
-///
-/// **Known problems:** When accessing C, users might want to store pointer
-/// sized objects in `extradata` arguments to save an allocation.
-///
-/// **Example:**
-/// ```rust
-/// let ptr: *const T = core::intrinsics::transmute('x')`
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes that can't ever be correct on any
+    /// architecture.
+    ///
+    /// **Why is this bad?** It's basically guaranteed to be undefined behaviour.
+    ///
+    /// **Known problems:** When accessing C, users might want to store pointer
+    /// sized objects in `extradata` arguments to save an allocation.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let ptr: *const T = core::intrinsics::transmute('x')`
+    /// ```
     pub WRONG_TRANSMUTE,
     correctness,
     "transmutes that are confusing at best, undefined behaviour at worst and always useless"
 }
 
-/// **What it does:** Checks for transmutes to the original type of the object
-/// and transmutes that could be a cast.
-///
-/// **Why is this bad?** Readability. The code tricks people into thinking that
-/// something complex is going on.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// core::intrinsics::transmute(t) // where the result type is the same as `t`'s
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes to the original type of the object
+    /// and transmutes that could be a cast.
+    ///
+    /// **Why is this bad?** Readability. The code tricks people into thinking that
+    /// something complex is going on.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// core::intrinsics::transmute(t) // where the result type is the same as `t`'s
+    /// ```
     pub USELESS_TRANSMUTE,
     complexity,
     "transmutes that have the same to and from types or could be a cast/coercion"
 }
 
-/// **What it does:** Checks for transmutes between a type `T` and `*T`.
-///
-/// **Why is this bad?** It's easy to mistakenly transmute between a type and a
-/// pointer to that type.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// core::intrinsics::transmute(t)` // where the result type is the same as
-/// `*t` or `&t`'s
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes between a type `T` and `*T`.
+    ///
+    /// **Why is this bad?** It's easy to mistakenly transmute between a type and a
+    /// pointer to that type.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// core::intrinsics::transmute(t)` // where the result type is the same as
+    /// `*t` or `&t`'s
+    /// ```
     pub CROSSPOINTER_TRANSMUTE,
     complexity,
     "transmutes that have to or from types that are a pointer to the other"
 }
 
-/// **What it does:** Checks for transmutes from a pointer to a reference.
-///
-/// **Why is this bad?** This can always be rewritten with `&` and `*`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let _: &T = std::mem::transmute(p); // where p: *const T
-/// // can be written:
-/// let _: &T = &*p;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from a pointer to a reference.
+    ///
+    /// **Why is this bad?** This can always be rewritten with `&` and `*`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _: &T = std::mem::transmute(p); // where p: *const T
+    /// // can be written:
+    /// let _: &T = &*p;
+    /// ```
     pub TRANSMUTE_PTR_TO_REF,
     complexity,
     "transmutes from a pointer to a reference type"
 }
 
-/// **What it does:** Checks for transmutes from an integer to a `char`.
-///
-/// **Why is this bad?** Not every integer is a Unicode scalar value.
-///
-/// **Known problems:**
-/// - [`from_u32`] which this lint suggests using is slower than `transmute`
-/// as it needs to validate the input.
-/// If you are certain that the input is always a valid Unicode scalar value,
-/// use [`from_u32_unchecked`] which is as fast as `transmute`
-/// but has a semantically meaningful name.
-/// - You might want to handle `None` returned from [`from_u32`] instead of calling `unwrap`.
-///
-/// [`from_u32`]: https://doc.rust-lang.org/std/char/fn.from_u32.html
-/// [`from_u32_unchecked`]: https://doc.rust-lang.org/std/char/fn.from_u32_unchecked.html
-///
-/// **Example:**
-/// ```rust
-/// let _: char = std::mem::transmute(x); // where x: u32
-/// // should be:
-/// let _ = std::char::from_u32(x).unwrap();
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from an integer to a `char`.
+    ///
+    /// **Why is this bad?** Not every integer is a Unicode scalar value.
+    ///
+    /// **Known problems:**
+    /// - [`from_u32`] which this lint suggests using is slower than `transmute`
+    /// as it needs to validate the input.
+    /// If you are certain that the input is always a valid Unicode scalar value,
+    /// use [`from_u32_unchecked`] which is as fast as `transmute`
+    /// but has a semantically meaningful name.
+    /// - You might want to handle `None` returned from [`from_u32`] instead of calling `unwrap`.
+    ///
+    /// [`from_u32`]: https://doc.rust-lang.org/std/char/fn.from_u32.html
+    /// [`from_u32_unchecked`]: https://doc.rust-lang.org/std/char/fn.from_u32_unchecked.html
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _: char = std::mem::transmute(x); // where x: u32
+    /// // should be:
+    /// let _ = std::char::from_u32(x).unwrap();
+    /// ```
     pub TRANSMUTE_INT_TO_CHAR,
     complexity,
     "transmutes from an integer to a `char`"
 }
 
-/// **What it does:** Checks for transmutes from a `&[u8]` to a `&str`.
-///
-/// **Why is this bad?** Not every byte slice is a valid UTF-8 string.
-///
-/// **Known problems:**
-/// - [`from_utf8`] which this lint suggests using is slower than `transmute`
-/// as it needs to validate the input.
-/// If you are certain that the input is always a valid UTF-8,
-/// use [`from_utf8_unchecked`] which is as fast as `transmute`
-/// but has a semantically meaningful name.
-/// - You might want to handle errors returned from [`from_utf8`] instead of calling `unwrap`.
-///
-/// [`from_utf8`]: https://doc.rust-lang.org/std/str/fn.from_utf8.html
-/// [`from_utf8_unchecked`]: https://doc.rust-lang.org/std/str/fn.from_utf8_unchecked.html
-///
-/// **Example:**
-/// ```rust
-/// let _: &str = std::mem::transmute(b); // where b: &[u8]
-/// // should be:
-/// let _ = std::str::from_utf8(b).unwrap();
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from a `&[u8]` to a `&str`.
+    ///
+    /// **Why is this bad?** Not every byte slice is a valid UTF-8 string.
+    ///
+    /// **Known problems:**
+    /// - [`from_utf8`] which this lint suggests using is slower than `transmute`
+    /// as it needs to validate the input.
+    /// If you are certain that the input is always a valid UTF-8,
+    /// use [`from_utf8_unchecked`] which is as fast as `transmute`
+    /// but has a semantically meaningful name.
+    /// - You might want to handle errors returned from [`from_utf8`] instead of calling `unwrap`.
+    ///
+    /// [`from_utf8`]: https://doc.rust-lang.org/std/str/fn.from_utf8.html
+    /// [`from_utf8_unchecked`]: https://doc.rust-lang.org/std/str/fn.from_utf8_unchecked.html
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _: &str = std::mem::transmute(b); // where b: &[u8]
+    /// // should be:
+    /// let _ = std::str::from_utf8(b).unwrap();
+    /// ```
     pub TRANSMUTE_BYTES_TO_STR,
     complexity,
     "transmutes from a `&[u8]` to a `&str`"
 }
 
-/// **What it does:** Checks for transmutes from an integer to a `bool`.
-///
-/// **Why is this bad?** This might result in an invalid in-memory representation of a `bool`.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let _: bool = std::mem::transmute(x); // where x: u8
-/// // should be:
-/// let _: bool = x != 0;
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from an integer to a `bool`.
+    ///
+    /// **Why is this bad?** This might result in an invalid in-memory representation of a `bool`.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _: bool = std::mem::transmute(x); // where x: u8
+    /// // should be:
+    /// let _: bool = x != 0;
+    /// ```
     pub TRANSMUTE_INT_TO_BOOL,
     complexity,
     "transmutes from an integer to a `bool`"
 }
 
-/// **What it does:** Checks for transmutes from an integer to a float.
-///
-/// **Why is this bad?** This might result in an invalid in-memory representation of a float.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// let _: f32 = std::mem::transmute(x); // where x: u32
-/// // should be:
-/// let _: f32 = f32::from_bits(x);
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes from an integer to a float.
+    ///
+    /// **Why is this bad?** This might result in an invalid in-memory representation of a float.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let _: f32 = std::mem::transmute(x); // where x: u32
+    /// // should be:
+    /// let _: f32 = f32::from_bits(x);
+    /// ```
     pub TRANSMUTE_INT_TO_FLOAT,
     complexity,
     "transmutes from an integer to a float"
 }
 
-/// **What it does:** Checks for transmutes to a potentially less-aligned type.
-///
-/// **Why is this bad?** This might result in undefined behavior.
-///
-/// **Known problems:** None.
-///
-/// **Example:**
-/// ```rust
-/// // u32 is 32-bit aligned; u8 is 8-bit aligned
-/// let _: u32 = unsafe { std::mem::transmute([0u8; 4]) };
-/// ```
 declare_clippy_lint! {
+    /// **What it does:** Checks for transmutes to a potentially less-aligned type.
+    ///
+    /// **Why is this bad?** This might result in undefined behavior.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// // u32 is 32-bit aligned; u8 is 8-bit aligned
+    /// let _: u32 = unsafe { std::mem::transmute([0u8; 4]) };
+    /// ```
     pub MISALIGNED_TRANSMUTE,
     complexity,
     "transmutes to a potentially less-aligned type"
@@ -244,7 +244,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                         arg.as_ty(cx.tcx.mk_ptr(rty)).as_ty(to_ty)
                                     };
 
-                                    db.span_suggestion(e.span, "try", sugg.to_string());
+                                    db.span_suggestion_with_applicability(
+                                        e.span,
+                                        "try",
+                                        sugg.to_string(),
+                                        Applicability::MachineApplicable,
+                                    );
                                 },
                             ),
                             (&ty::TyInt(_), &ty::TyRawPtr(_)) | (&ty::TyUint(_), &ty::TyRawPtr(_)) => {
@@ -254,7 +259,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                     e.span,
                                     "transmute from an integer to a pointer",
                                     |db| if let Some(arg) = sugg::Sugg::hir_opt(cx, &args[0]) {
-                                        db.span_suggestion(e.span, "try", arg.as_ty(&to_ty.to_string()).to_string());
+                                        db.span_suggestion_with_applicability(
+                                            e.span,
+                                            "try",
+                                            arg.as_ty(&to_ty.to_string()).to_string(),
+                                            Applicability::MachineApplicable,
+                                        );
                                     },
                                 )
                             },
@@ -311,7 +321,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                         arg.as_ty(&format!("{} {}", cast, get_type_snippet(cx, qpath, to_ref_ty.ty)))
                                     };
 
-                                    db.span_suggestion(e.span, "try", sugg::make_unop(deref, arg).to_string());
+                                    db.span_suggestion_with_applicability(
+                                        e.span,
+                                        "try",
+                                        sugg::make_unop(deref, arg).to_string(),
+                                        Applicability::MachineApplicable,
+                                    );
                                 },
                             ),
                             (&ty::TyInt(ast::IntTy::I32), &ty::TyChar) |
@@ -327,10 +342,11 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                     } else {
                                         arg
                                     };
-                                    db.span_suggestion(
+                                    db.span_suggestion_with_applicability(
                                         e.span,
                                         "consider using",
                                         format!("std::char::from_u32({}).unwrap()", arg.to_string()),
+                                        Applicability::MaybeIncorrect,
                                     );
                                 },
                             ),
@@ -352,7 +368,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                             e.span,
                                             &format!("transmute from a `{}` to a `{}`", from_ty, to_ty),
                                             |db| {
-                                                db.span_suggestion(
+                                                db.span_suggestion_with_applicability(
                                                     e.span,
                                                     "consider using",
                                                     format!(
@@ -360,6 +376,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                                         postfix,
                                                         snippet(cx, args[0].span, ".."),
                                                     ),
+                                                    Applicability::MaybeIncorrect,
                                                 );
                                             }
                                         )
@@ -375,10 +392,11 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                     |db| {
                                         let arg = sugg::Sugg::hir(cx, &args[0], "..");
                                         let zero = sugg::Sugg::NonParen(Cow::from("0"));
-                                        db.span_suggestion(
+                                        db.span_suggestion_with_applicability(
                                             e.span,
                                             "consider using",
                                             sugg::make_binop(ast::BinOpKind::Ne, &arg, &zero).to_string(),
+                                            Applicability::MachineApplicable,
                                         );
                                     },
                                 )
@@ -401,10 +419,11 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                         } else {
                                             arg
                                         };
-                                        db.span_suggestion(
+                                        db.span_suggestion_with_applicability(
                                             e.span,
                                             "consider using",
                                             format!("{}::from_bits({})", to_ty, arg.to_string()),
+                                            Applicability::MachineApplicable,
                                         );
                                     },
                                 )
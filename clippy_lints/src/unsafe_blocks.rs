@@ -0,0 +1,286 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::lint::*;
+use rustc::ty;
+use syntax::ast::NodeId;
+use syntax::codemap::Span;
+use utils::{in_macro, item_header_span, resolve_node, span_help_and_lint, span_lint_and_then, type_is_unsafe_function};
+use utils::conf::Threshold;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unsafe` blocks without a `// SAFETY:` comment
+    /// on the lines just before them.
+    ///
+    /// **Why is this bad?** The comment explains why the block is sound, so the
+    /// reviewers can check it, and the block can be checked again when the code
+    /// around it changes.
+    ///
+    /// **Known problems:** Only line comments are recognized.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// let value = unsafe { *ptr };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// // SAFETY: `ptr` comes from `Box::into_raw` and is not freed yet
+    /// let value = unsafe { *ptr };
+    /// ```
+    pub UNDOCUMENTED_UNSAFE_BLOCKS,
+    restriction,
+    "`unsafe` block without a `// SAFETY:` comment"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unsafe` blocks with more statements without
+    /// an unsafe operation than the `safe-statements-in-unsafe-threshold`
+    /// configuration allows.
+    ///
+    /// **Why is this bad?** The unsafe operations are hard to find for the
+    /// reviewers, and the other statements can use unsafe operations later
+    /// without anyone noticing.
+    ///
+    /// **Known problems:** A statement is counted as safe if it has no unsafe
+    /// operation itself, even if it maintains the invariants the unsafe
+    /// operations rely on.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// unsafe {
+    ///     let len = v.len();
+    ///     let first = *v.as_ptr();
+    ///     let last = v[len - 1];
+    ///     println!("{} {}", first, last);
+    ///     first + last
+    /// }
+    /// ```
+    pub OVERSIZED_UNSAFE_BLOCK,
+    restriction,
+    "`unsafe` block with many statements without an unsafe operation"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `unsafe fn` with more statements without an
+    /// unsafe operation than the `safe-statements-in-unsafe-threshold`
+    /// configuration allows. The functions without any unsafe operation are not
+    /// linted, they are `unsafe` for the contract they require.
+    ///
+    /// **Why is this bad?** The whole body of an `unsafe fn` may use unsafe
+    /// operations, so the reviewers must check every statement of it.
+    ///
+    /// **Known problems:** Same as `oversized_unsafe_block`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// unsafe fn read_all(ptr: *const u8, len: usize) -> Vec<u8> {
+    ///     let mut v = Vec::with_capacity(len);
+    ///     for i in 0..len {
+    ///         v.push(*ptr.offset(i as isize));
+    ///     }
+    ///     v.shrink_to_fit();
+    ///     log_read(len);
+    ///     v
+    /// }
+    /// ```
+    pub OVERSIZED_UNSAFE_FN,
+    restriction,
+    "`unsafe fn` with many statements without an unsafe operation"
+}
+
+#[derive(Clone)]
+pub struct UnsafeBlocks {
+    threshold: Threshold,
+}
+
+impl UnsafeBlocks {
+    pub fn new(threshold: Threshold) -> Self {
+        Self {
+            threshold,
+        }
+    }
+}
+
+impl LintPass for UnsafeBlocks {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNDOCUMENTED_UNSAFE_BLOCKS, OVERSIZED_UNSAFE_BLOCK, OVERSIZED_UNSAFE_FN)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnsafeBlocks {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let block = match expr.node {
+            ExprBlock(ref block) => block,
+            _ => return,
+        };
+        if block.rules != BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided) {
+            return;
+        }
+        if in_macro(expr.span) {
+            return;
+        }
+
+        if !has_safety_comment(cx, expr.span) {
+            span_help_and_lint(
+                cx,
+                UNDOCUMENTED_UNSAFE_BLOCKS,
+                expr.span,
+                "`unsafe` block without a `// SAFETY:` comment",
+                "explain why the block is sound in a `// SAFETY:` comment on the line before it",
+            );
+        }
+
+        let threshold = self.threshold.at(cx.sess().codemap(), expr.span);
+        if let Some((safe, first)) = count_safe_statements(cx, block) {
+            if safe > threshold {
+                span_lint_and_then(
+                    cx,
+                    OVERSIZED_UNSAFE_BLOCK,
+                    expr.span,
+                    &format!("this `unsafe` block has {} statements without an unsafe operation", safe),
+                    |db| {
+                        db.span_note(first, "the first unsafe operation is here");
+                        db.help("keep only the unsafe operations in the block");
+                    },
+                );
+            }
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        _: NodeId,
+    ) {
+        let unsafety = match kind {
+            FnKind::ItemFn(_, _, unsafety, ..) => unsafety,
+            FnKind::Method(_, sig, ..) => sig.unsafety,
+            FnKind::Closure(_) => return,
+        };
+        if unsafety != Unsafety::Unsafe || in_macro(span) {
+            return;
+        }
+        let block = match body.value.node {
+            ExprBlock(ref block) => block,
+            _ => return,
+        };
+
+        let threshold = self.threshold.at(cx.sess().codemap(), span);
+        if let Some((safe, first)) = count_safe_statements(cx, block) {
+            if safe > threshold {
+                span_lint_and_then(
+                    cx,
+                    OVERSIZED_UNSAFE_FN,
+                    item_header_span(cx, span),
+                    &format!("this `unsafe fn` has {} statements without an unsafe operation", safe),
+                    |db| {
+                        db.span_note(first, "the first unsafe operation is here");
+                        db.help("move the statements without unsafe operations to safe functions");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns true if the lines just before `span`, or the start of its line, have a comment with
+/// `SAFETY:`.
+fn has_safety_comment(cx: &LateContext, span: Span) -> bool {
+    let lo = cx.sess().codemap().lookup_char_pos(span.lo());
+    // `let x = /* SAFETY: ... */ unsafe { .. }`, line numbers in `Loc` are 1-based
+    if let Some(line) = lo.file.get_line(lo.line - 1) {
+        if line.chars().take(lo.col.0).collect::<String>().contains("SAFETY:") {
+            return true;
+        }
+    }
+    let mut line = lo.line - 1;
+    while line > 0 {
+        line -= 1;
+        match lo.file.get_line(line) {
+            Some(ref text) if text.trim_left().starts_with("//") => if text.contains("SAFETY:") {
+                return true;
+            },
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// The number of statements of `block`, counting its final expression, without an unsafe
+/// operation, and the first unsafe operation. `None` if there is no unsafe operation.
+fn count_safe_statements<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>, block: &'tcx Block) -> Option<(u64, Span)> {
+    let mut safe = 0;
+    let mut first = None;
+    let stmts = block.stmts.iter().filter_map(|stmt| match stmt.node {
+        StmtDecl(ref decl, _) => match decl.node {
+            DeclLocal(ref local) => local.init.as_ref().map(|init| &**init),
+            DeclItem(_) => None,
+        },
+        StmtExpr(ref expr, _) | StmtSemi(ref expr, _) => Some(&**expr),
+    });
+    for expr in stmts.chain(block.expr.as_ref().map(|expr| &**expr)) {
+        let mut visitor = UnsafeOpVisitor { cx, found: None };
+        visitor.visit_expr(expr);
+        match visitor.found {
+            Some(span) => if first.is_none() {
+                first = Some(span);
+            },
+            None => safe += 1,
+        }
+    }
+    first.map(|first| (safe, first))
+}
+
+/// Finds the first unsafe operation of an expression: dereferencing a raw pointer, calling an
+/// `unsafe` function, using a `static mut`, reading a union field or inline assembly.
+struct UnsafeOpVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    found: Option<Span>,
+}
+
+impl<'a, 'tcx> UnsafeOpVisitor<'a, 'tcx> {
+    fn is_unsafe_op(&self, expr: &'tcx Expr) -> bool {
+        let tables = self.cx.tables;
+        match expr.node {
+            ExprUnary(UnDeref, ref ptr) => match tables.expr_ty(ptr).sty {
+                ty::TyRawPtr(_) => true,
+                _ => false,
+            },
+            ExprCall(ref fun, _) => type_is_unsafe_function(self.cx, tables.expr_ty(fun)),
+            ExprMethodCall(..) => tables.type_dependent_defs().get(expr.hir_id).map_or(false, |def| {
+                type_is_unsafe_function(self.cx, self.cx.tcx.type_of(def.def_id()))
+            }),
+            ExprPath(ref qpath) => match resolve_node(self.cx, qpath, expr.hir_id) {
+                Def::Static(_, mutable) => mutable,
+                _ => false,
+            },
+            ExprField(ref base, _) => match tables.expr_ty_adjusted(base).sty {
+                ty::TyAdt(adt, _) => adt.is_union(),
+                _ => false,
+            },
+            ExprInlineAsm(..) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UnsafeOpVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.found.is_some() {
+            return;
+        }
+        if self.is_unsafe_op(expr) {
+            self.found = Some(expr.span);
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir)
+    }
+}
@@ -0,0 +1,215 @@
+//! Support for `cargo clippy --baseline` and `cargo clippy --write-baseline`.
+//!
+//! A baseline file lists known findings, one per line, as `lint<TAB>file<TAB>fingerprint`. The
+//! fingerprint is a hash of the code the lint points to, so findings still match when the code
+//! around them moves. A finding listed `n` times suppresses `n` identical findings.
+//!
+//! `cargo clippy` passes the files to the driver with these environment variables:
+//!
+//! * `CLIPPY_BASELINE`: the findings of this baseline file are not reported,
+//! * `CLIPPY_WRITE_BASELINE`: a directory where each driver process writes the findings it
+//!   reports, `cargo clippy` merges them into the baseline file afterwards.
+
+use rustc::lint::Lint;
+use rustc::session::Session;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Mutex;
+use syntax::codemap::{CodeMap, FileName, Span};
+
+/// A finding of a lint.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Finding {
+    /// The name of the lint, in lowercase.
+    lint: String,
+    /// The file of the finding, relative to the directory `cargo` runs the compiler in.
+    file: String,
+    /// The hash of the code the lint points to.
+    fingerprint: String,
+}
+
+impl Finding {
+    /// The finding of `lint` in `file`, pointing to `code`.
+    pub fn new(lint: &str, file: &str, code: &str) -> Self {
+        // whitespace is ignored so reformatting the code does not invalidate the baseline
+        let code = code.split_whitespace().collect::<Vec<_>>().join(" ");
+        Self {
+            lint: lint.to_owned(),
+            file: file.to_owned(),
+            fingerprint: format!("{:016x}", fnv1a(code.as_bytes())),
+        }
+    }
+
+    /// Parse a line of a baseline file.
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.split('\t');
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(lint), Some(file), Some(fingerprint), None) => Some(Self {
+                lint: lint.to_owned(),
+                file: file.to_owned(),
+                fingerprint: fingerprint.to_owned(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// The line of a baseline file for this finding.
+    pub fn to_line(&self) -> String {
+        format!("{}\t{}\t{}", self.lint, self.file, self.fingerprint)
+    }
+}
+
+/// The findings of a baseline file, with the number of times each one is listed.
+#[derive(Debug, Default)]
+pub struct Baseline(BTreeMap<Finding, usize>);
+
+impl Baseline {
+    /// Parse the content of a baseline file.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut findings = BTreeMap::new();
+        for line in content.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let finding = Finding::parse(line).ok_or_else(|| format!("invalid baseline entry `{}`", line))?;
+            *findings.entry(finding).or_insert(0) += 1;
+        }
+        Ok(Baseline(findings))
+    }
+
+    /// Returns true if `finding` is in the baseline and must not be reported. Each listed finding
+    /// only suppresses one identical finding.
+    pub fn suppress(&mut self, finding: &Finding) -> bool {
+        match self.0.get_mut(finding) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            },
+            _ => false,
+        }
+    }
+}
+
+/// What the driver does with the findings.
+enum Mode {
+    /// Neither `CLIPPY_BASELINE` nor `CLIPPY_WRITE_BASELINE` is set, or recording failed.
+    Disabled,
+    /// The findings to suppress.
+    Suppress(Baseline),
+    /// The file the findings of this process are appended to.
+    Record(PathBuf),
+    /// The baseline could not be read, the error is reported by `start`.
+    Unreadable(String),
+}
+
+lazy_static! {
+    static ref MODE: Mutex<Mode> = Mutex::new(mode_from_env());
+}
+
+/// Read the environment variables set by `cargo clippy`.
+fn mode_from_env() -> Mode {
+    if let Some(dir) = env::var_os("CLIPPY_WRITE_BASELINE") {
+        return Mode::Record(Path::new(&dir).join(format!("{}.txt", process::id())));
+    }
+    match env::var_os("CLIPPY_BASELINE") {
+        Some(file) => match read(Path::new(&file)) {
+            Ok(baseline) => Mode::Suppress(baseline),
+            Err(err) => {
+                let file = file.to_string_lossy();
+                Mode::Unreadable(format!("could not read the baseline `{}`: {}", file, err))
+            },
+        },
+        None => Mode::Disabled,
+    }
+}
+
+/// Read a baseline file.
+fn read(path: &Path) -> io::Result<Baseline> {
+    let mut content = String::new();
+    fs::File::open(path)?.read_to_string(&mut content)?;
+    Baseline::parse(&content).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Called when the lints are registered, reports an unreadable baseline. When recording, create
+/// the file of this process even if it reports nothing, so `cargo clippy` can tell that the crate
+/// was checked.
+pub fn start(sess: &Session) {
+    let mut mode = MODE.lock().expect("no threading -> mutex always safe");
+    let failed = match *mode {
+        Mode::Record(ref path) => match fs::OpenOptions::new().create(true).append(true).open(path) {
+            Ok(_) => false,
+            Err(err) => {
+                sess.err(&format!("could not record the findings in `{}`: {}", path.display(), err));
+                true
+            },
+        },
+        Mode::Unreadable(ref err) => {
+            sess.err(err);
+            true
+        },
+        Mode::Disabled | Mode::Suppress(_) => false,
+    };
+    if failed {
+        *mode = Mode::Disabled;
+    }
+}
+
+/// Called for every finding about to be reported. Returns true if the finding is in the baseline
+/// and must not be reported.
+pub fn is_known(sess: &Session, lint: &'static Lint, span: Span) -> bool {
+    let mut mode = MODE.lock().expect("no threading -> mutex always safe");
+    let failed = match *mode {
+        Mode::Disabled | Mode::Unreadable(_) => false,
+        Mode::Suppress(ref mut baseline) => return baseline.suppress(&finding(sess.codemap(), lint, span)),
+        Mode::Record(ref path) => {
+            let line = finding(sess.codemap(), lint, span).to_line();
+            let written = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| writeln!(file, "{}", line));
+            match written {
+                Ok(()) => false,
+                Err(err) => {
+                    sess.err(&format!("could not record a finding in `{}`: {}", path.display(), err));
+                    true
+                },
+            }
+        },
+    };
+    // the error is only reported once
+    if failed {
+        *mode = Mode::Disabled;
+    }
+    false
+}
+
+/// Build the finding of `lint` at `span`.
+fn finding(codemap: &CodeMap, lint: &'static Lint, span: Span) -> Finding {
+    let code = codemap.span_to_snippet(span).unwrap_or_default();
+    Finding::new(&lint.name_lower(), &file_name(codemap, span), &code)
+}
+
+/// The file of `span`, relative to the directory `cargo` runs the compiler in, with `/` as
+/// separator.
+pub fn file_name(codemap: &CodeMap, span: Span) -> String {
+    match codemap.lookup_char_pos(span.lo()).file.name {
+        FileName::Real(ref path) => {
+            let cwd = env::current_dir().unwrap_or_default();
+            path.strip_prefix(&cwd).unwrap_or(path).to_string_lossy().replace('\\', "/")
+        },
+        ref name => name.to_string(),
+    }
+}
+
+/// The 64 bit FNV-1a hash, unlike `DefaultHasher` it is guaranteed to be stable across Rust
+/// versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -2,9 +2,14 @@
 
 #![deny(missing_docs_in_private_items)]
 
-use std::{env, fmt, fs, io, path};
+use glob;
+use std::{env, fmt, fs, io, mem, path};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Read;
 use syntax::{ast, codemap};
+use syntax::codemap::{BytePos, CodeMap, FileName, Span, NO_EXPANSION};
+use syntax::util::lev_distance::lev_distance;
 use toml;
 use std::sync::Mutex;
 
@@ -47,7 +52,33 @@ pub enum Error {
         &'static str,
     ),
     /// There is an unknown key is the file.
-    UnknownKey(String),
+    UnknownKey(
+        /// The unknown key.
+        KeyPath,
+        /// The closest known key, if any is close enough.
+        Option<String>,
+    ),
+    /// The value of a key cannot be used.
+    Invalid(KeyPath, String),
+    /// A threshold is too small to make sense.
+    TooSmall(
+        /// The threshold.
+        KeyPath,
+        /// Its value.
+        u64,
+        /// The smallest allowed value.
+        u64,
+    ),
+}
+
+impl Error {
+    /// The key the error is about, if any.
+    pub fn key(&self) -> Option<&KeyPath> {
+        match *self {
+            Error::UnknownKey(ref key, _) | Error::Invalid(ref key, _) | Error::TooSmall(ref key, ..) => Some(key),
+            Error::Io(_) | Error::Toml(_) | Error::Type(..) => None,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -58,11 +89,174 @@ impl fmt::Display for Error {
             Error::Type(key, expected, got) => {
                 write!(f, "`{}` is expected to be a `{}` but is a `{}`", key, expected, got)
             },
-            Error::UnknownKey(ref key) => write!(f, "unknown key `{}`", key),
+            Error::UnknownKey(ref key, None) => write!(f, "unknown key `{}`", key),
+            Error::UnknownKey(ref key, Some(ref suggestion)) => {
+                write!(f, "unknown key `{}`, did you mean `{}`?", key, suggestion)
+            },
+            Error::Invalid(ref key, ref err) => write!(f, "invalid value for `{}`: {}", key, err),
+            Error::TooSmall(ref key, value, min) => {
+                write!(f, "`{}` must be at least {} but is {}", key, min, value)
+            },
+        }
+    }
+}
+
+/// The location of a key in the configuration file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyPath {
+    /// The `[table]` or `[[array]]` the key is in, with the index of the section for arrays.
+    pub table: Option<(&'static str, Option<usize>)>,
+    /// The name of the key.
+    pub name: String,
+}
+
+impl KeyPath {
+    /// A key outside of any table.
+    pub fn top(name: String) -> Self {
+        Self { table: None, name }
+    }
+
+    /// A key in a table or in a section of an array of tables.
+    pub fn in_table(table: &'static str, index: Option<usize>, name: String) -> Self {
+        Self {
+            table: Some((table, index)),
+            name,
+        }
+    }
+
+    /// The byte range of the key in `source`, found by looking for a `name =` line in the right
+    /// section. Keys of inline tables are not found.
+    pub fn find(&self, source: &str) -> Option<(usize, usize)> {
+        // the section we are in: its name and, for arrays of tables, how many we have seen
+        let mut section: Option<(&str, usize)> = None;
+        let mut offset = 0;
+        for line in source.split('\n') {
+            let start = offset;
+            offset += line.len() + 1;
+            let trimmed = line.trim();
+            if trimmed.starts_with('[') {
+                let name = trimmed.trim_matches(|c| c == '[' || c == ']').trim();
+                let index = match section {
+                    Some((current, index)) if current == name => index + 1,
+                    _ => 0,
+                };
+                section = Some((name, index));
+                continue;
+            }
+            let in_section = match (self.table, section) {
+                (None, None) => true,
+                (Some((table, None)), Some((current, _))) => table == current,
+                (Some((table, Some(index))), Some((current, current_index))) => {
+                    table == current && index == current_index
+                },
+                _ => false,
+            };
+            if !in_section {
+                continue;
+            }
+            let key = match trimmed.find('=') {
+                Some(eq) => trimmed[..eq].trim().trim_matches('"'),
+                None => continue,
+            };
+            if key == self.name {
+                let lo = start + line.find(key).expect("the key is in the line");
+                return Some((lo, lo + key.len()));
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Display for KeyPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.table {
+            Some((table, _)) => write!(f, "{}.{}", table, self.name),
+            None => self.name.fmt(f),
+        }
+    }
+}
+
+/// A key of the configuration file, as declared in `define_Conf!`.
+#[derive(Clone, Copy, Debug)]
+pub struct ConfKey {
+    /// The name of the key, with underscores.
+    pub name: &'static str,
+    /// The type of the value.
+    pub ty: &'static str,
+    /// The default value, as written in `define_Conf!`.
+    pub default: &'static str,
+    /// The documentation, starting with `Lint: LINT_NAME.` for the keys configuring lints.
+    pub doc: &'static str,
+}
+
+impl ConfKey {
+    /// The name of the key as written in the configuration file.
+    pub fn kebab_name(&self) -> String {
+        self.name.replace('_', "-")
+    }
+
+    /// The names of the lints this key configures, in lowercase.
+    pub fn lints(&self) -> Vec<String> {
+        let doc = self.doc.trim();
+        if !doc.starts_with("Lint: ") {
+            return Vec::new();
+        }
+        doc["Lint: ".len()..]
+            .split('.')
+            .next()
+            .unwrap_or("")
+            .split(',')
+            .map(|lint| lint.trim().to_lowercase())
+            .collect()
+    }
+
+    /// The documentation without the `Lint: LINT_NAME.` prefix.
+    pub fn description(&self) -> &'static str {
+        let doc = self.doc.trim();
+        if doc.starts_with("Lint: ") {
+            doc.splitn(2, ". ").nth(1).unwrap_or("")
+        } else {
+            doc
         }
     }
 }
 
+/// The span of `key` in the configuration file at `path`. The file is added to the codemap so the
+/// diagnostics can show it.
+pub fn key_span(codemap: &CodeMap, path: &path::Path, key: &KeyPath) -> Option<Span> {
+    let filemap = match codemap.get_filemap(&FileName::Real(path.to_path_buf())) {
+        Some(filemap) => filemap,
+        None => codemap.load_file(path).ok()?,
+    };
+    let (lo, hi) = key.find(filemap.src.as_ref()?)?;
+    Some(Span::new(
+        filemap.start_pos + BytePos(lo as u32),
+        filemap.start_pos + BytePos(hi as u32),
+        NO_EXPANSION,
+    ))
+}
+
+/// The smallest sensible value of some thresholds, below it the lint triggers everywhere.
+const MINIMUMS: &[(&str, u64)] = &[
+    ("cognitive-complexity-threshold", 1),
+    ("cyclomatic-complexity-threshold", 1),
+    ("duplicate-code-threshold", 1),
+    ("single-char-binding-names-threshold", 1),
+    ("type-complexity-threshold", 1),
+];
+
+/// Suggest the known name closest to `name`, if it looks like a typo.
+pub fn suggest<'a, I: IntoIterator<Item = &'a str>>(name: &str, candidates: I) -> Option<String> {
+    // `_` instead of `-` is the most common mistake
+    let name = name.replace('_', "-");
+    candidates
+        .into_iter()
+        .map(|candidate| (lev_distance(&name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= ::std::cmp::max(name.len(), 3) / 3)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate.to_owned())
+}
+
 impl From<io::Error> for Error {
     fn from(e: io::Error) -> Self {
         Error::Io(e)
@@ -74,20 +268,39 @@ lazy_static! {
 }
 
 macro_rules! define_Conf {
-    ($(#[$doc: meta] ($rust_name: ident, $rust_name_str: expr, $default: expr => $($ty: tt)+),)+) => {
-        pub use self::helpers::Conf;
+    ($(#[doc = $doc: expr] ($rust_name: ident, $rust_name_str: expr, $default: expr => $($ty: tt)+),)+) => {
+        pub use self::helpers::{Conf, KEYS};
         mod helpers {
             /// Type used to store lint configuration.
             #[derive(Deserialize)]
             #[serde(rename_all="kebab-case")]
             #[serde(deny_unknown_fields)]
             pub struct Conf {
-                $(#[$doc] #[serde(default=$rust_name_str)] #[serde(with=$rust_name_str)]
+                $(#[doc = $doc] #[serde(default=$rust_name_str)] #[serde(with=$rust_name_str)]
                           pub $rust_name: define_Conf!(TY $($ty)+),)+
                 #[allow(dead_code)]
                 #[serde(default)]
                 third_party: Option<::toml::Value>,
+                /// The path of the configuration file, paths in the configuration are relative to its
+                /// directory.
+                #[serde(skip)]
+                pub path: Option<::std::path::PathBuf>,
+                /// The top-level keys set in the configuration file.
+                #[serde(skip)]
+                pub keys: Vec<String>,
+                /// The unknown keys of the configuration file with the closest known key, reported by
+                /// the `unknown_configuration_key` lint.
+                #[serde(skip)]
+                pub unknown_keys: Vec<(::utils::conf::KeyPath, Option<String>)>,
             }
+
+            /// Every configuration key.
+            pub const KEYS: &[::utils::conf::ConfKey] = &[$(::utils::conf::ConfKey {
+                name: $rust_name_str,
+                ty: stringify!($($ty)+),
+                default: stringify!($default),
+                doc: $doc,
+            },)+];
             $(
                 mod $rust_name {
                     use serde;
@@ -96,8 +309,9 @@ macro_rules! define_Conf {
                     -> Result<define_Conf!(TY $($ty)+), D::Error> {
                         type T = define_Conf!(TY $($ty)+);
                         Ok(T::deserialize(deserializer).unwrap_or_else(|e| {
+                            let key = ::utils::conf::KeyPath::top($rust_name_str.replace('_', "-"));
                             ::utils::conf::ERRORS.lock().expect("no threading here")
-                                                        .push(::utils::conf::Error::Toml(e.to_string()));
+                                                        .push(::utils::conf::Error::Invalid(key, e.to_string()));
                             super::$rust_name()
                         }))
                     }
@@ -146,6 +360,8 @@ define_Conf! {
     (blacklisted_names, "blacklisted_names", ["foo", "bar", "baz", "quux"] => Vec<String>),
     /// Lint: CYCLOMATIC_COMPLEXITY. The maximum cyclomatic complexity a function can have
     (cyclomatic_complexity_threshold, "cyclomatic_complexity_threshold", 25 => u64),
+    /// Lint: COGNITIVE_COMPLEXITY. The maximum cognitive complexity a function can have
+    (cognitive_complexity_threshold, "cognitive_complexity_threshold", 25 => u64),
     /// Lint: DOC_MARKDOWN. The list of words this lint should not consider as identifiers needing ticks
     (doc_valid_idents, "doc_valid_idents", [
         "KiB", "MiB", "GiB", "TiB", "PiB", "EiB",
@@ -179,14 +395,274 @@ define_Conf! {
     (verbose_bit_mask_threshold, "verbose_bit_mask_threshold", 1 => u64),
     /// Lint: DECIMAL_LITERAL_REPRESENTATION. The lower bound for linting decimal literals
     (literal_representation_threshold, "literal_representation_threshold", 16384 => u64),
+    /// Lint: OVERSIZED_UNSAFE_BLOCK, OVERSIZED_UNSAFE_FN. The maximum number of statements without unsafe operations
+    (safe_statements_in_unsafe_threshold, "safe_statements_in_unsafe_threshold", 3 => u64),
+    /// Lint: DUPLICATE_CODE. The minimum number of statements of the blocks compared across the crate
+    (duplicate_code_threshold, "duplicate_code_threshold", 5 => u64),
+    /// The level of lints and lint groups in the whole crate
+    (lints, "lints", ::std::collections::BTreeMap::new()
+        => ::std::collections::BTreeMap<String, ::utils::conf::LintLevel>),
+    /// Lint levels and thresholds that only apply to the files matching some paths
+    (overrides, "overrides", Vec::new() => Vec<::utils::conf::PathOverride>),
+    /// Lint: DISALLOWED_METHODS. The functions and methods that must not be used, eg. `std::env::set_var`
+    (disallowed_methods, "disallowed_methods", Vec::new() => Vec<::utils::conf::DisallowedPath>),
+    /// Lint: DISALLOWED_TYPES. The types that must not be used, eg. `std::collections::HashMap`
+    (disallowed_types, "disallowed_types", Vec::new() => Vec<::utils::conf::DisallowedPath>),
+    /// Lint: PRINT_LITERAL, USELESS_FORMAT. The macros forwarding their arguments to `format_args!`, eg. `log::info`
+    (format_macros, "format_macros", Vec::new() => Vec<String>),
+}
+
+/// A lint level of the `[lints]` table of the configuration file.
+///
+/// ```toml
+/// [lints]
+/// clippy_pedantic = "warn"
+/// needless_return = "allow"
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// `#![allow(...)]`
+    Allow,
+    /// `#![warn(...)]`
+    Warn,
+    /// `#![deny(...)]`
+    Deny,
+    /// `#![forbid(...)]`
+    Forbid,
+}
+
+impl LintLevel {
+    /// The name of the attribute setting this level.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+            LintLevel::Forbid => "forbid",
+        }
+    }
+}
+
+/// An item of `disallowed-methods` or `disallowed-types`: a path, with an optional reason shown
+/// with the warnings.
+///
+/// ```toml
+/// disallowed-methods = [
+///     "std::env::set_var",
+///     { path = "chrono::Local::now", reason = "use the clock of the context" },
+/// ]
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DisallowedPath {
+    /// Only the path.
+    Simple(String),
+    /// The path and the reason.
+    WithReason {
+        path: String,
+        #[serde(default)]
+        reason: Option<String>,
+    },
+}
+
+impl DisallowedPath {
+    /// The path, eg. `std::env::set_var`.
+    pub fn path(&self) -> &str {
+        match *self {
+            DisallowedPath::Simple(ref path) | DisallowedPath::WithReason { ref path, .. } => path,
+        }
+    }
+
+    /// Why the path is disallowed.
+    pub fn reason(&self) -> Option<&str> {
+        match *self {
+            DisallowedPath::WithReason { reason: Some(ref reason), .. } => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// The segments of the path, eg. `["std", "env", "set_var"]`.
+    pub fn segments(&self) -> Vec<&str> {
+        self.path().split("::").map(str::trim).collect()
+    }
+}
+
+/// An `[[overrides]]` section of the configuration file.
+///
+/// ```toml
+/// [[overrides]]
+/// paths = ["tests/**"]
+/// allow = ["option_unwrap_used"]
+///
+/// [[overrides]]
+/// paths = ["src/legacy/**"]
+/// too-many-arguments-threshold = 10
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct PathOverride {
+    /// Glob patterns of the files this section applies to, relative to the configuration file.
+    pub paths: Vec<String>,
+    /// Lints and lint groups allowed in the matching files.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// Lints and lint groups that warn in the matching files.
+    #[serde(default)]
+    pub warn: Vec<String>,
+    /// Lints and lint groups denied in the matching files.
+    #[serde(default)]
+    pub deny: Vec<String>,
+    /// Thresholds overridden in the matching files, by configuration key. Filled by `read` from the
+    /// other keys of the section once they are checked.
+    #[serde(skip)]
+    pub thresholds: BTreeMap<String, u64>,
+    /// The keys of the section other than the paths and the levels, as written in the file.
+    #[serde(flatten)]
+    other: BTreeMap<String, toml::Value>,
+}
+
+/// The compiled `[[overrides]]` sections of the configuration file.
+#[derive(Debug, Default)]
+pub struct PathOverrides {
+    /// The directory the globs are relative to.
+    root: path::PathBuf,
+    /// The directory relative file names are relative to.
+    cwd: path::PathBuf,
+    /// Each section with its compiled globs, in the order of the file.
+    overrides: Vec<(Vec<glob::Pattern>, PathOverride)>,
+    /// The indices of the sections matching each file already looked up.
+    cache: RefCell<HashMap<path::PathBuf, Vec<usize>>>,
+}
+
+impl PathOverrides {
+    /// Compile the globs of the configuration. Invalid globs have already been reported by `read`
+    /// and never match.
+    pub fn new(conf: &Conf) -> Self {
+        let cwd = env::current_dir().unwrap_or_default();
+        let root = conf.path
+            .as_ref()
+            .and_then(|path| path.parent())
+            .map_or_else(|| cwd.clone(), |dir| cwd.join(dir));
+        let overrides = conf.overrides
+            .iter()
+            .map(|o| {
+                let globs = o.paths.iter().filter_map(|p| glob::Pattern::new(p).ok()).collect();
+                (globs, o.clone())
+            })
+            .collect();
+
+        Self {
+            root,
+            cwd,
+            overrides,
+            cache: RefCell::default(),
+        }
+    }
+
+    /// Returns true if there is no `[[overrides]]` section.
+    pub fn is_empty(&self) -> bool {
+        self.overrides.is_empty()
+    }
+
+    /// The sections matching the given file with their index, in the order of the configuration
+    /// file.
+    pub fn matching(&self, file: &path::Path) -> Vec<(usize, &PathOverride)> {
+        let mut cache = self.cache.borrow_mut();
+        let indices = cache
+            .entry(file.to_path_buf())
+            .or_insert_with(|| self.matching_indices(file));
+        indices.iter().map(|&i| (i, &self.overrides[i].1)).collect()
+    }
+
+    /// The indices of the sections whose globs match `file`.
+    fn matching_indices(&self, file: &path::Path) -> Vec<usize> {
+        let file = self.cwd.join(file);
+        let relative = match file.strip_prefix(&self.root) {
+            Ok(relative) => relative,
+            Err(_) => return Vec::new(),
+        };
+        let options = glob::MatchOptions {
+            case_sensitive: true,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        };
+
+        self.overrides
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(ref globs, _))| globs.iter().any(|g| g.matches_path_with(relative, &options)))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// The sections matching the file `span` is in.
+    pub fn matching_span(&self, codemap: &CodeMap, span: Span) -> Vec<(usize, &PathOverride)> {
+        match codemap.lookup_char_pos(span.lo()).file.name {
+            FileName::Real(ref path) => self.matching(path),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A threshold of the configuration file that can be overridden for some paths.
+#[derive(Clone, Debug)]
+pub struct Threshold {
+    /// The configuration key, eg. `too-many-arguments-threshold`.
+    key: &'static str,
+    /// The value outside of the `[[overrides]]` sections.
+    default: u64,
+    /// The `[[overrides]]` sections.
+    overrides: ::std::rc::Rc<PathOverrides>,
+}
+
+impl Threshold {
+    /// Create a threshold for the given configuration key.
+    pub fn new(key: &'static str, default: u64, overrides: ::std::rc::Rc<PathOverrides>) -> Self {
+        Self {
+            key,
+            default,
+            overrides,
+        }
+    }
+
+    /// The threshold in the file `span` is in. The last matching section wins.
+    ///
+    /// The sections matching each file are only computed once.
+    pub fn at(&self, codemap: &CodeMap, span: Span) -> u64 {
+        if self.overrides.is_empty() {
+            return self.default;
+        }
+        self.overrides
+            .matching_span(codemap, span)
+            .into_iter()
+            .filter_map(|(_, o)| o.thresholds.get(self.key).cloned())
+            .last()
+            .unwrap_or(self.default)
+    }
+
+    /// The threshold outside of the `[[overrides]]` sections.
+    pub fn default_value(&self) -> u64 {
+        self.default
+    }
 }
 
 /// Search for the configuration file.
+///
+/// The `CLIPPY_CONF_FILE` environment variable can name the file to use, relative to the current
+/// directory. Otherwise the file is searched from the directory of the manifest upwards, there is
+/// none if the compiler does not run under `cargo`.
 pub fn lookup_conf_file() -> io::Result<Option<path::PathBuf>> {
     /// Possible filename to search for.
     const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
 
-    let mut current = path::PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set"));
+    if let Some(file) = env::var_os("CLIPPY_CONF_FILE") {
+        return Ok(Some(file.into()));
+    }
+
+    let mut current = match env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => path::PathBuf::from(dir),
+        None => return Ok(None),
+    };
 
     loop {
         for config_file_name in &CONFIG_FILE_NAMES {
@@ -218,9 +694,116 @@ fn default(errors: Vec<Error>) -> (Conf, Vec<Error>) {
     (toml::from_str("").expect("we never error on empty config files"), errors)
 }
 
+impl Default for Conf {
+    fn default() -> Self {
+        default(Vec::new()).0
+    }
+}
+
+/// The names of the keys of type `u64`, as written in the configuration file.
+fn threshold_keys() -> Vec<String> {
+    KEYS.iter()
+        .filter(|key| key.ty == "u64")
+        .map(ConfKey::kebab_name)
+        .collect()
+}
+
+/// Returns true if `value` is not below the minimum of the threshold `key`, otherwise reports it.
+fn check_range(key: KeyPath, value: u64, errors: &mut Vec<Error>) -> bool {
+    match MINIMUMS.iter().find(|&&(name, _)| name == key.name) {
+        Some(&(_, min)) if value < min => {
+            errors.push(Error::TooSmall(key, value, min));
+            false
+        },
+        _ => true,
+    }
+}
+
+/// Remove the unknown top-level keys and the thresholds out of range from `value`, so the rest of
+/// the configuration can still be used.
+fn validate_top_level(value: &mut toml::Value, errors: &mut Vec<Error>) {
+    let table = match *value {
+        toml::Value::Table(ref mut table) => table,
+        _ => return,
+    };
+    let known = KEYS.iter()
+        .map(ConfKey::kebab_name)
+        .chain(Some("third-party".to_owned()))
+        .collect::<Vec<_>>();
+    let thresholds = threshold_keys();
+
+    let mut invalid = Vec::new();
+    for (key, value) in table.iter() {
+        if !known.contains(key) {
+            let suggestion = suggest(key, known.iter().map(String::as_str));
+            errors.push(Error::UnknownKey(KeyPath::top(key.clone()), suggestion));
+            invalid.push(key.clone());
+        } else if thresholds.contains(key) {
+            match value.as_integer() {
+                Some(value) if value >= 0 => if !check_range(KeyPath::top(key.clone()), value as u64, errors) {
+                    invalid.push(key.clone());
+                },
+                // negative values and other types are reported when deserializing
+                _ => (),
+            }
+        }
+    }
+    for key in invalid {
+        table.remove(&key);
+    }
+}
+
+/// Check the `[[overrides]]` sections, the invalid thresholds are removed.
+fn validate_overrides(overrides: &mut [PathOverride], errors: &mut Vec<Error>) {
+    let thresholds = threshold_keys();
+    for (i, o) in overrides.iter_mut().enumerate() {
+        let key = |name: &str| KeyPath::in_table("overrides", Some(i), name.to_owned());
+        if o.paths.is_empty() {
+            errors.push(Error::Invalid(key("paths"), "the list of paths must not be empty".to_owned()));
+        }
+        for p in &o.paths {
+            if let Err(e) = glob::Pattern::new(p) {
+                errors.push(Error::Invalid(key("paths"), format!("invalid glob `{}`: {}", p, e)));
+            }
+        }
+
+        for (name, value) in mem::replace(&mut o.other, BTreeMap::new()) {
+            if !thresholds.contains(&name) {
+                let candidates = thresholds
+                    .iter()
+                    .map(String::as_str)
+                    .chain(["paths", "allow", "warn", "deny"].iter().cloned());
+                let suggestion = suggest(&name, candidates);
+                errors.push(Error::UnknownKey(key(&name), suggestion));
+                continue;
+            }
+            match value.as_integer() {
+                Some(value) if value >= 0 => if check_range(key(&name), value as u64, errors) {
+                    o.thresholds.insert(name, value as u64);
+                },
+                _ => errors.push(Error::Invalid(key(&name), "expected a non-negative integer".to_owned())),
+            }
+        }
+    }
+}
+
+/// Check the paths of `disallowed-methods` or `disallowed-types`, the invalid ones are removed.
+fn validate_disallowed(key: &str, paths: &mut Vec<DisallowedPath>, errors: &mut Vec<Error>) {
+    paths.retain(|path| {
+        // the first segment is the crate
+        let valid = path.segments().len() >= 2 && path.segments().iter().all(|segment| !segment.is_empty());
+        if !valid {
+            let msg = format!("`{}` is not a path of the form `crate::item`", path.path());
+            errors.push(Error::Invalid(KeyPath::top(key.to_owned()), msg));
+        }
+        valid
+    });
+}
+
 /// Read the `toml` configuration file.
 ///
-/// In case of error, the function tries to continue as much as possible.
+/// In case of error, the function tries to continue as much as possible: unknown keys and invalid
+/// values are ignored, the rest of the file is still used.
 pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
     let path = if let Some(path) = path {
         path
@@ -241,25 +824,40 @@ pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
         Err(err) => return default(vec![err.into()]),
     };
 
+    let mut value = match toml::from_str::<toml::Value>(&file) {
+        Ok(value) => value,
+        Err(e) => return default(vec![Error::Toml(e.to_string())]),
+    };
+    let mut errors = Vec::new();
+    validate_top_level(&mut value, &mut errors);
+    let keys = value
+        .as_table()
+        .map(|table| table.keys().cloned().collect())
+        .unwrap_or_default();
+
     assert!(
         ERRORS
             .lock()
             .expect("no threading -> mutex always safe")
             .is_empty()
     );
-    match toml::from_str(&file) {
-        Ok(toml) => (
-            toml,
-            ERRORS
-                .lock()
-                .expect("no threading -> mutex always safe")
-                .split_off(0),
-        ),
+    let conf = value.try_into::<Conf>();
+    errors.extend(
+        ERRORS
+            .lock()
+            .expect("no threading -> mutex always safe")
+            .split_off(0),
+    );
+    match conf {
+        Ok(mut toml) => {
+            toml.path = Some(path.to_path_buf());
+            toml.keys = keys;
+            validate_overrides(&mut toml.overrides, &mut errors);
+            validate_disallowed("disallowed-methods", &mut toml.disallowed_methods, &mut errors);
+            validate_disallowed("disallowed-types", &mut toml.disallowed_types, &mut errors);
+            (toml, errors)
+        },
         Err(e) => {
-            let mut errors = ERRORS
-                .lock()
-                .expect("no threading -> mutex always safe")
-                .split_off(0);
             errors.push(Error::Toml(e.to_string()));
             default(errors)
         },
@@ -1,9 +1,13 @@
 use consts::{constant_simple, constant_context};
 use rustc::lint::*;
 use rustc::hir::*;
+use rustc::hir::def::Def;
+use std::cell::RefCell;
 use std::hash::{Hash, Hasher};
+use std::collections::HashMap;
 use std::collections::hash_map::DefaultHasher;
-use syntax::ast::Name;
+use std::mem;
+use syntax::ast::{Name, NodeId};
 use syntax::ptr::P;
 use utils::differing_macro_contexts;
 
@@ -19,6 +23,13 @@ pub struct SpanlessEq<'a, 'tcx: 'a> {
     /// If is true, never consider as equal expressions containing function
     /// calls.
     ignore_fn: bool,
+    /// If is true, the names of the local variables and the values of the
+    /// literals are not compared, a local variable only has to match the same
+    /// one of the other side everywhere.
+    ignore_locals_and_literals: bool,
+    /// The local variables of the left side matched with the ones of the
+    /// right side, and the reverse.
+    locals: RefCell<(HashMap<NodeId, NodeId>, HashMap<NodeId, NodeId>)>,
 }
 
 impl<'a, 'tcx: 'a> SpanlessEq<'a, 'tcx> {
@@ -26,13 +37,25 @@ impl<'a, 'tcx: 'a> SpanlessEq<'a, 'tcx> {
         Self {
             cx,
             ignore_fn: false,
+            ignore_locals_and_literals: false,
+            locals: RefCell::default(),
         }
     }
 
     pub fn ignore_fn(self) -> Self {
         Self {
-            cx: self.cx,
             ignore_fn: true,
+            ..self
+        }
+    }
+
+    /// Compare the code like `SpanlessHash::ignore_locals_and_literals` hashes
+    /// it. The constants are not evaluated either, so this does not need the
+    /// typeck tables of the compared code.
+    pub fn ignore_locals_and_literals(self) -> Self {
+        Self {
+            ignore_locals_and_literals: true,
+            ..self
         }
     }
 
@@ -64,9 +87,11 @@ impl<'a, 'tcx: 'a> SpanlessEq<'a, 'tcx> {
             return false;
         }
 
-        if let (Some(l), Some(r)) = (constant_simple(self.cx, left), constant_simple(self.cx, right)) {
-            if l == r {
-                return true;
+        if !self.ignore_locals_and_literals {
+            if let (Some(l), Some(r)) = (constant_simple(self.cx, left), constant_simple(self.cx, right)) {
+                if l == r {
+                    return true;
+                }
             }
         }
 
@@ -81,8 +106,11 @@ impl<'a, 'tcx: 'a> SpanlessEq<'a, 'tcx> {
             },
             (&ExprBlock(ref l), &ExprBlock(ref r)) => self.eq_block(l, r),
             (&ExprBinary(l_op, ref ll, ref lr), &ExprBinary(r_op, ref rl, ref rr)) => {
+                // the local variables matched by the first try must not stay matched for the second one
+                let locals = self.locals.borrow().clone();
                 l_op.node == r_op.node && self.eq_expr(ll, rl) && self.eq_expr(lr, rr)
                     || swap_binop(l_op.node, ll, lr).map_or(false, |(l_op, ll, lr)| {
+                        *self.locals.borrow_mut() = locals;
                         l_op == r_op.node && self.eq_expr(ll, rl) && self.eq_expr(lr, rr)
                     })
             },
@@ -103,7 +131,11 @@ impl<'a, 'tcx: 'a> SpanlessEq<'a, 'tcx> {
             (&ExprIf(ref lc, ref lt, ref le), &ExprIf(ref rc, ref rt, ref re)) => {
                 self.eq_expr(lc, rc) && self.eq_expr(&**lt, &**rt) && both(le, re, |l, r| self.eq_expr(l, r))
             },
-            (&ExprLit(ref l), &ExprLit(ref r)) => l.node == r.node,
+            (&ExprLit(ref l), &ExprLit(ref r)) => if self.ignore_locals_and_literals {
+                mem::discriminant(&l.node) == mem::discriminant(&r.node)
+            } else {
+                l.node == r.node
+            },
             (&ExprLoop(ref lb, ref ll, ref lls), &ExprLoop(ref rb, ref rl, ref rls)) => {
                 lls == rls && self.eq_block(lb, rb) && both(ll, rl, |l, r| l.name.as_str() == r.name.as_str())
             },
@@ -161,7 +193,12 @@ impl<'a, 'tcx: 'a> SpanlessEq<'a, 'tcx> {
                 self.eq_qpath(lp, rp) && over(la, ra, |l, r| self.eq_pat(l, r)) && ls == rs
             },
             (&PatKind::Binding(ref lb, _, ref li, ref lp), &PatKind::Binding(ref rb, _, ref ri, ref rp)) => {
-                lb == rb && li.node.as_str() == ri.node.as_str() && both(lp, rp, |l, r| self.eq_pat(l, r))
+                let same_name = if self.ignore_locals_and_literals {
+                    self.eq_local(left.id, right.id)
+                } else {
+                    li.node.as_str() == ri.node.as_str()
+                };
+                lb == rb && same_name && both(lp, rp, |l, r| self.eq_pat(l, r))
             },
             (&PatKind::Path(ref l), &PatKind::Path(ref r)) => self.eq_qpath(l, r),
             (&PatKind::Lit(ref l), &PatKind::Lit(ref r)) => self.eq_expr(l, r),
@@ -182,6 +219,16 @@ impl<'a, 'tcx: 'a> SpanlessEq<'a, 'tcx> {
     }
 
     fn eq_qpath(&self, left: &QPath, right: &QPath) -> bool {
+        if self.ignore_locals_and_literals {
+            if let (&QPath::Resolved(None, ref lpath), &QPath::Resolved(None, ref rpath)) = (left, right) {
+                match (lpath.def, rpath.def) {
+                    (Def::Local(l), Def::Local(r)) | (Def::Upvar(l, ..), Def::Upvar(r, ..)) => {
+                        return self.eq_local(l, r)
+                    },
+                    _ => (),
+                }
+            }
+        }
         match (left, right) {
             (&QPath::Resolved(ref lty, ref lpath), &QPath::Resolved(ref rty, ref rpath)) => {
                 both(lty, rty, |l, r| self.eq_ty(l, r)) && self.eq_path(lpath, rpath)
@@ -249,6 +296,14 @@ impl<'a, 'tcx: 'a> SpanlessEq<'a, 'tcx> {
     fn eq_type_binding(&self, left: &TypeBinding, right: &TypeBinding) -> bool {
         left.name == right.name && self.eq_ty(&left.ty, &right.ty)
     }
+
+    /// Check whether the local variable `left` is matched with `right`, matching them if neither
+    /// is matched yet.
+    fn eq_local(&self, left: NodeId, right: NodeId) -> bool {
+        let mut locals = self.locals.borrow_mut();
+        let (ref mut left_to_right, ref mut right_to_left) = *locals;
+        *left_to_right.entry(left).or_insert(right) == right && *right_to_left.entry(right).or_insert(left) == left
+    }
 }
 
 fn swap_binop<'a>(binop: BinOp_, lhs: &'a Expr, rhs: &'a Expr) -> Option<(BinOp_, &'a Expr, &'a Expr)> {
@@ -290,6 +345,11 @@ pub struct SpanlessHash<'a, 'tcx: 'a> {
     /// Context used to evaluate constant expressions.
     cx: &'a LateContext<'a, 'tcx>,
     s: DefaultHasher,
+    /// If is true, the names of the local variables and the values of the
+    /// literals are not hashed, only the order the local variables are used in.
+    ignore_locals_and_literals: bool,
+    /// The local variables seen so far, and their order.
+    locals: HashMap<NodeId, usize>,
 }
 
 impl<'a, 'tcx: 'a> SpanlessHash<'a, 'tcx> {
@@ -297,6 +357,15 @@ impl<'a, 'tcx: 'a> SpanlessHash<'a, 'tcx> {
         Self {
             cx,
             s: DefaultHasher::new(),
+            ignore_locals_and_literals: false,
+            locals: HashMap::new(),
+        }
+    }
+
+    pub fn ignore_locals_and_literals(self) -> Self {
+        Self {
+            ignore_locals_and_literals: true,
+            ..self
         }
     }
 
@@ -318,8 +387,10 @@ impl<'a, 'tcx: 'a> SpanlessHash<'a, 'tcx> {
 
     #[allow(many_single_char_names)]
     pub fn hash_expr(&mut self, e: &Expr) {
-        if let Some(e) = constant_simple(self.cx, e) {
-            return e.hash(&mut self.s);
+        if !self.ignore_locals_and_literals {
+            if let Some(e) = constant_simple(self.cx, e) {
+                return e.hash(&mut self.s);
+            }
         }
 
         match e.node {
@@ -427,7 +498,11 @@ impl<'a, 'tcx: 'a> SpanlessHash<'a, 'tcx> {
             ExprLit(ref l) => {
                 let c: fn(_) -> _ = ExprLit;
                 c.hash(&mut self.s);
-                l.hash(&mut self.s);
+                if self.ignore_locals_and_literals {
+                    mem::discriminant(&l.node).hash(&mut self.s);
+                } else {
+                    l.hash(&mut self.s);
+                }
             },
             ExprLoop(ref b, ref i, _) => {
                 let c: fn(_, _, _) -> _ = ExprLoop;
@@ -474,7 +549,13 @@ impl<'a, 'tcx: 'a> SpanlessHash<'a, 'tcx> {
             ExprPath(ref qpath) => {
                 let c: fn(_) -> _ = ExprPath;
                 c.hash(&mut self.s);
-                self.hash_qpath(qpath);
+                match *qpath {
+                    QPath::Resolved(None, ref path) if self.ignore_locals_and_literals => match path.def {
+                        Def::Local(id) | Def::Upvar(id, ..) => self.hash_local(id),
+                        _ => self.hash_path(path),
+                    },
+                    _ => self.hash_qpath(qpath),
+                }
             },
             ExprStruct(ref path, ref fields, ref expr) => {
                 let c: fn(_, _, _) -> _ = ExprStruct;
@@ -541,6 +622,12 @@ impl<'a, 'tcx: 'a> SpanlessHash<'a, 'tcx> {
         }
     }
 
+    /// Hash a local variable by the order it is first used in.
+    fn hash_local(&mut self, id: NodeId) {
+        let next = self.locals.len();
+        self.locals.entry(id).or_insert(next).hash(&mut self.s);
+    }
+
     pub fn hash_name(&mut self, n: &Name) {
         n.as_str().hash(&mut self.s);
     }
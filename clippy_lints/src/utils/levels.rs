@@ -0,0 +1,268 @@
+//! Apply the lint levels of the configuration file by adding `allow`/`warn`/`deny` attributes to
+//! the parsed crate.
+//!
+//! The plugin registry given to `register_plugins` can add lints but cannot change their levels:
+//! only attributes can, and only `clippy-driver` can edit the crate before the levels are computed.
+//! The compiler plugin does not apply the levels of the configuration file.
+//!
+//! The levels set with `-A`/`-W`/`-D`/`-F` on the command line take precedence over the
+//! configuration file, and the attributes written in the code take precedence over both.
+
+use rustc::lint::{LintId, LintStore};
+use rustc::session::Session;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use syntax::ast::{AttrId, Attribute, Crate, Ident, Item, ItemKind, MetaItem, NestedMetaItemKind};
+use syntax::attr;
+use syntax::codemap::{respan, CodeMap, FileName, Span};
+use syntax::util::lev_distance::lev_distance;
+use utils::conf::{key_span, Conf, KeyPath, LintLevel, PathOverride, PathOverrides};
+
+/// What the attributes are built from.
+struct Levels<'a> {
+    codemap: &'a CodeMap,
+    store: &'a LintStore,
+    conf: &'a Conf,
+    overrides: PathOverrides,
+    /// The lints whose level is set on the command line, directly or through a group.
+    command_line: HashSet<LintId>,
+}
+
+/// Add the lint level attributes of the `[lints]` table to the crate root and the ones of the
+/// `[[overrides]]` sections to the modules in the matching files.
+///
+/// The attributes are inserted before the existing ones so attributes written in the code still
+/// win, and the `[[overrides]]` sections win over the `[lints]` table. The lints whose level is set
+/// on the command line are left out. Unknown lints and lint groups are reported and ignored.
+pub fn apply(krate: &mut Crate, sess: &Session, conf: &Conf) {
+    let store = sess.lint_store.borrow();
+    report_unknown(sess, &store, conf);
+
+    let levels = Levels {
+        codemap: sess.codemap(),
+        store: &store,
+        conf,
+        overrides: PathOverrides::new(conf),
+        command_line: sess.opts
+            .lint_opts
+            .iter()
+            .filter_map(|&(ref name, _)| store.find_lints(name).ok())
+            .flat_map(|lints| lints)
+            .collect(),
+    };
+
+    let mut attrs = crate_attrs(&levels, krate.span);
+    let root_file = file_of(levels.codemap, krate.module.inner);
+    if let Some(ref file) = root_file {
+        let sections = levels.overrides.matching(file);
+        attrs.extend(level_attrs(&levels, &sections, krate.span, attr::mk_attr_inner));
+    }
+    prepend(&mut krate.attrs, attrs);
+
+    if levels.overrides.is_empty() {
+        return;
+    }
+    for item in &mut krate.module.items {
+        apply_item(&levels, item, root_file.as_ref());
+    }
+}
+
+/// Report the lints and lint groups of the configuration file which do not exist.
+fn report_unknown(sess: &Session, store: &LintStore, conf: &Conf) {
+    let mut names = conf.lints
+        .keys()
+        .map(|name| (name, KeyPath::in_table("lints", None, name.clone())))
+        .collect::<Vec<_>>();
+    for (i, section) in conf.overrides.iter().enumerate() {
+        for &(level, lints) in &[("allow", &section.allow), ("warn", &section.warn), ("deny", &section.deny)] {
+            names.extend(
+                lints
+                    .iter()
+                    .map(|name| (name, KeyPath::in_table("overrides", Some(i), level.to_owned()))),
+            );
+        }
+    }
+
+    let known = store
+        .get_lints()
+        .iter()
+        .map(|&(lint, _)| lint.name_lower())
+        .chain(store.get_lint_groups().into_iter().map(|(name, _, _)| name.to_owned()))
+        .collect::<Vec<_>>();
+    for (name, key) in names {
+        if store.find_lints(name).is_ok() {
+            continue;
+        }
+        let suggestion = known
+            .iter()
+            .map(|candidate| (lev_distance(name, candidate), candidate))
+            .filter(|&(distance, _)| distance <= ::std::cmp::max(name.len(), 3) / 3)
+            .min_by_key(|&(distance, _)| distance);
+        let msg = match suggestion {
+            Some((_, candidate)) => format!(
+                "unknown lint `{}` in Clippy's configuration file, did you mean `{}`?",
+                name,
+                candidate
+            ),
+            None => format!("unknown lint `{}` in Clippy's configuration file", name),
+        };
+        let span = conf.path
+            .as_ref()
+            .and_then(|path| key_span(sess.codemap(), path, &key));
+        match span {
+            Some(span) => sess.span_err(span, &msg),
+            None => sess.err(&msg),
+        }
+    }
+}
+
+/// The names to put in an attribute for the configured lint or lint group `name`: none if it is
+/// unknown or if its level is set on the command line, the lints of a group not set on the
+/// command line if some of them are.
+fn configured_names(levels: &Levels, name: &str) -> Vec<String> {
+    let lints = match levels.store.find_lints(name) {
+        Ok(lints) => lints,
+        Err(_) => return Vec::new(),
+    };
+    let remaining = lints
+        .iter()
+        .filter(|lint| !levels.command_line.contains(lint))
+        .collect::<Vec<_>>();
+    if remaining.len() == lints.len() {
+        vec![name.to_owned()]
+    } else {
+        remaining.iter().map(|lint| lint.to_string()).collect()
+    }
+}
+
+/// Add the attributes to `item` if it is a module whose contents are in another file than its
+/// parent, then recurse into it.
+fn apply_item(levels: &Levels, item: &mut Item, parent_file: Option<&PathBuf>) {
+    let attrs = if let ItemKind::Mod(ref mut module) = item.node {
+        let file = file_of(levels.codemap, module.inner);
+        for child in &mut module.items {
+            apply_item(levels, child, file.as_ref());
+        }
+        match file {
+            Some(ref file) if Some(file) != parent_file => {
+                let sections = levels.overrides.matching(file);
+                level_attrs(levels, &sections, item.span, attr::mk_attr_outer)
+            },
+            _ => return,
+        }
+    } else {
+        return;
+    };
+    prepend(&mut item.attrs, attrs);
+}
+
+/// Build the crate attributes of the `[lints]` table.
+///
+/// Lint groups come first so the levels of individual lints take precedence over the levels of
+/// the groups they belong to.
+fn crate_attrs(levels: &Levels, span: Span) -> Vec<Attribute> {
+    let groups = levels
+        .store
+        .get_lint_groups()
+        .into_iter()
+        .map(|(name, _, _)| name)
+        .collect::<Vec<_>>();
+    let (group_levels, lint_levels): (Vec<_>, Vec<_>) = levels
+        .conf
+        .lints
+        .iter()
+        .partition(|&(name, _)| groups.contains(&name.as_str()));
+
+    // the notes about the level of a lint point to its key in the configuration file
+    let lint_span = |name: &str| {
+        levels
+            .conf
+            .path
+            .as_ref()
+            .and_then(|path| key_span(levels.codemap, path, &KeyPath::in_table("lints", None, name.to_owned())))
+            .unwrap_or(span)
+    };
+
+    let mut attrs = Vec::new();
+    for configured in &[group_levels, lint_levels] {
+        for &level in &[LintLevel::Allow, LintLevel::Warn, LintLevel::Deny, LintLevel::Forbid] {
+            let lints = configured
+                .iter()
+                .filter(|&&(_, &l)| l == level)
+                .flat_map(|&(name, _)| {
+                    let span = lint_span(name);
+                    configured_names(levels, name).into_iter().map(move |name| (name, span))
+                })
+                .collect::<Vec<_>>();
+            attrs.extend(level_attr(level.as_str(), &lints, span, attr::mk_attr_inner));
+        }
+    }
+    attrs
+}
+
+/// The file `span` starts in, if it is a real file.
+fn file_of(codemap: &CodeMap, span: Span) -> Option<PathBuf> {
+    match codemap.lookup_char_pos(span.lo()).file.name {
+        FileName::Real(ref path) => Some(path.clone()),
+        _ => None,
+    }
+}
+
+/// Build the `allow`/`warn`/`deny` attributes of the sections, in the order of the configuration
+/// file so that the last section wins.
+fn level_attrs<F>(levels: &Levels, sections: &[(usize, &PathOverride)], span: Span, mk_attr: F) -> Vec<Attribute>
+where
+    F: Fn(Span, AttrId, MetaItem) -> Attribute,
+{
+    let mut attrs = Vec::new();
+    for &(i, section) in sections {
+        for &(level, ref lints) in &[("allow", &section.allow), ("warn", &section.warn), ("deny", &section.deny)] {
+            if lints.is_empty() {
+                continue;
+            }
+            // the notes about the level of a lint point to the list in the configuration file
+            let lint_span = levels
+                .conf
+                .path
+                .as_ref()
+                .and_then(|path| {
+                    key_span(levels.codemap, path, &KeyPath::in_table("overrides", Some(i), level.to_owned()))
+                })
+                .unwrap_or(span);
+            let lints = lints
+                .iter()
+                .flat_map(|lint| configured_names(levels, lint))
+                .map(|lint| (lint, lint_span))
+                .collect::<Vec<_>>();
+            attrs.extend(level_attr(level, &lints, span, &mk_attr));
+        }
+    }
+    attrs
+}
+
+/// Build a `level(lints...)` attribute, or nothing if there are no lints.
+fn level_attr<F>(level: &str, lints: &[(String, Span)], span: Span, mk_attr: F) -> Option<Attribute>
+where
+    F: Fn(Span, AttrId, MetaItem) -> Attribute,
+{
+    if lints.is_empty() {
+        return None;
+    }
+    let lints = lints
+        .iter()
+        .map(|&(ref lint, lint_span)| {
+            let word = attr::mk_spanned_word_item(lint_span, Ident::from_str(lint));
+            respan(lint_span, NestedMetaItemKind::MetaItem(word))
+        })
+        .collect();
+    let meta = attr::mk_list_item(span, Ident::from_str(level), lints);
+    Some(mk_attr(span, attr::mk_attr_id(), meta))
+}
+
+/// Insert `new` before the attributes in `attrs`.
+fn prepend(attrs: &mut Vec<Attribute>, mut new: Vec<Attribute>) {
+    if !new.is_empty() {
+        new.append(attrs);
+        *attrs = new;
+    }
+}
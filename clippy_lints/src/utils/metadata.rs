@@ -0,0 +1,448 @@
+//! The lint registry: every lint with its group and documentation, as declared with
+//! `declare_clippy_lint!`. The lint groups are built from it, and the documentation is available
+//! offline.
+
+use rustc::lint::Lint;
+use std::fmt::Write;
+use syntax::util::lev_distance::lev_distance;
+use utils::conf::{ConfKey, KEYS};
+
+/// A lint as declared with `declare_clippy_lint!`.
+#[derive(Clone, Copy)]
+pub struct LintMetadata {
+    /// The lint itself.
+    pub lint: &'static &'static Lint,
+    /// The group, eg. `style` or `pedantic`.
+    pub group: &'static str,
+    /// The lines of the doc comments of the lint: "What it does", "Why is this bad", "Known
+    /// problems" and "Example".
+    pub docs: &'static [&'static str],
+}
+
+/// A lint declared with `declare_deprecated_lint!`.
+#[derive(Clone, Copy)]
+pub struct DeprecatedLint {
+    /// The lint itself.
+    pub lint: &'static &'static Lint,
+    /// Why the lint has been deprecated.
+    pub reason: &'static str,
+}
+
+impl DeprecatedLint {
+    /// The name of the lint, in lowercase.
+    pub fn name(&self) -> String {
+        self.lint.name_lower()
+    }
+}
+
+/// Every lint declared with `declare_clippy_lint!`, by module. A lint missing here is in no lint
+/// group and has no documentation, `tests/lint_registry.rs` checks that every lint of the lint
+/// passes is listed.
+pub static LINTS: &[&LintMetadata] = &[
+    &::approx_const::APPROX_CONSTANT::INFO,
+    &::arithmetic::FLOAT_ARITHMETIC::INFO,
+    &::arithmetic::INTEGER_ARITHMETIC::INFO,
+    &::array_indexing::INDEXING_SLICING::INFO,
+    &::array_indexing::OUT_OF_BOUNDS_INDEXING::INFO,
+    &::assign_ops::ASSIGN_OPS::INFO,
+    &::assign_ops::ASSIGN_OP_PATTERN::INFO,
+    &::assign_ops::MISREFACTORED_ASSIGN_OP::INFO,
+    &::attrs::DEPRECATED_SEMVER::INFO,
+    &::attrs::EMPTY_LINE_AFTER_OUTER_ATTR::INFO,
+    &::attrs::INLINE_ALWAYS::INFO,
+    &::attrs::USELESS_ATTRIBUTE::INFO,
+    &::bit_mask::BAD_BIT_MASK::INFO,
+    &::bit_mask::INEFFECTIVE_BIT_MASK::INFO,
+    &::bit_mask::VERBOSE_BIT_MASK::INFO,
+    &::blacklisted_name::BLACKLISTED_NAME::INFO,
+    &::block_in_if_condition::BLOCK_IN_IF_CONDITION_EXPR::INFO,
+    &::block_in_if_condition::BLOCK_IN_IF_CONDITION_STMT::INFO,
+    &::booleans::LOGIC_BUG::INFO,
+    &::booleans::NONMINIMAL_BOOL::INFO,
+    &::bytecount::NAIVE_BYTECOUNT::INFO,
+    &::cognitive_complexity::COGNITIVE_COMPLEXITY::INFO,
+    &::collapsible_if::COLLAPSIBLE_IF::INFO,
+    &::const_static_lifetime::CONST_STATIC_LIFETIME::INFO,
+    &::copies::IFS_SAME_COND::INFO,
+    &::copies::IF_SAME_THEN_ELSE::INFO,
+    &::copies::MATCH_SAME_ARMS::INFO,
+    &::cyclomatic_complexity::CYCLOMATIC_COMPLEXITY::INFO,
+    &::derive::DERIVE_HASH_XOR_EQ::INFO,
+    &::derive::EXPL_IMPL_CLONE_ON_COPY::INFO,
+    &::disallowed::DISALLOWED_METHODS::INFO,
+    &::disallowed::DISALLOWED_TYPES::INFO,
+    &::doc::DOC_MARKDOWN::INFO,
+    &::doc::INVALID_DOC_CODE_BLOCK::INFO,
+    &::doc::MISSING_ERRORS_DOC::INFO,
+    &::doc::MISSING_PANICS_DOC::INFO,
+    &::doc::MISSING_SAFETY_DOC::INFO,
+    &::double_comparison::DOUBLE_COMPARISONS::INFO,
+    &::double_parens::DOUBLE_PARENS::INFO,
+    &::drop_forget_ref::DROP_COPY::INFO,
+    &::drop_forget_ref::DROP_REF::INFO,
+    &::drop_forget_ref::FORGET_COPY::INFO,
+    &::drop_forget_ref::FORGET_REF::INFO,
+    &::duplicate_code::DUPLICATE_CODE::INFO,
+    &::else_if_without_else::ELSE_IF_WITHOUT_ELSE::INFO,
+    &::empty_enum::EMPTY_ENUM::INFO,
+    &::entry::MAP_ENTRY::INFO,
+    &::enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT::INFO,
+    &::enum_glob_use::ENUM_GLOB_USE::INFO,
+    &::enum_variants::ENUM_VARIANT_NAMES::INFO,
+    &::enum_variants::MODULE_INCEPTION::INFO,
+    &::enum_variants::PUB_ENUM_VARIANT_NAMES::INFO,
+    &::enum_variants::STUTTER::INFO,
+    &::eq_op::EQ_OP::INFO,
+    &::eq_op::OP_REF::INFO,
+    &::erasing_op::ERASING_OP::INFO,
+    &::escape::BOXED_LOCAL::INFO,
+    &::eta_reduction::REDUNDANT_CLOSURE::INFO,
+    &::eval_order_dependence::DIVERGING_SUB_EXPRESSION::INFO,
+    &::eval_order_dependence::EVAL_ORDER_DEPENDENCE::INFO,
+    &::explicit_write::EXPLICIT_WRITE::INFO,
+    &::fallible_impl_from::FALLIBLE_IMPL_FROM::INFO,
+    &::format::NAMED_ARGUMENT_USED_POSITIONALLY::INFO,
+    &::format::USELESS_FORMAT::INFO,
+    &::formatting::POSSIBLE_MISSING_COMMA::INFO,
+    &::formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING::INFO,
+    &::formatting::SUSPICIOUS_ELSE_FORMATTING::INFO,
+    &::functions::NOT_UNSAFE_PTR_ARG_DEREF::INFO,
+    &::functions::TOO_MANY_ARGUMENTS::INFO,
+    &::identity_conversion::IDENTITY_CONVERSION::INFO,
+    &::identity_op::IDENTITY_OP::INFO,
+    &::if_let_redundant_pattern_matching::IF_LET_REDUNDANT_PATTERN_MATCHING::INFO,
+    &::if_not_else::IF_NOT_ELSE::INFO,
+    &::infinite_iter::INFINITE_ITER::INFO,
+    &::infinite_iter::MAYBE_INFINITE_ITER::INFO,
+    &::inline_fn_without_body::INLINE_FN_WITHOUT_BODY::INFO,
+    &::int_plus_one::INT_PLUS_ONE::INFO,
+    &::invalid_ref::INVALID_REF::INFO,
+    &::items_after_statements::ITEMS_AFTER_STATEMENTS::INFO,
+    &::large_enum_variant::LARGE_ENUM_VARIANT::INFO,
+    &::len_zero::LEN_WITHOUT_IS_EMPTY::INFO,
+    &::len_zero::LEN_ZERO::INFO,
+    &::let_if_seq::USELESS_LET_IF_SEQ::INFO,
+    &::lifetimes::NEEDLESS_LIFETIMES::INFO,
+    &::lifetimes::UNUSED_LIFETIMES::INFO,
+    &::literal_representation::DECIMAL_LITERAL_REPRESENTATION::INFO,
+    &::literal_representation::INCONSISTENT_DIGIT_GROUPING::INFO,
+    &::literal_representation::LARGE_DIGIT_GROUPS::INFO,
+    &::literal_representation::UNREADABLE_LITERAL::INFO,
+    &::lock_guards::DOUBLE_LOCK::INFO,
+    &::lock_guards::GUARD_ACROSS_BLOCKING_CALL::INFO,
+    &::loops::EMPTY_LOOP::INFO,
+    &::loops::EXPLICIT_COUNTER_LOOP::INFO,
+    &::loops::EXPLICIT_INTO_ITER_LOOP::INFO,
+    &::loops::EXPLICIT_ITER_LOOP::INFO,
+    &::loops::FOR_KV_MAP::INFO,
+    &::loops::FOR_LOOP_OVER_OPTION::INFO,
+    &::loops::FOR_LOOP_OVER_RESULT::INFO,
+    &::loops::ITER_NEXT_LOOP::INFO,
+    &::loops::MANUAL_MEMCPY::INFO,
+    &::loops::MUT_RANGE_BOUND::INFO,
+    &::loops::NEEDLESS_RANGE_LOOP::INFO,
+    &::loops::NEVER_LOOP::INFO,
+    &::loops::REVERSE_RANGE_LOOP::INFO,
+    &::loops::UNUSED_COLLECT::INFO,
+    &::loops::WHILE_IMMUTABLE_CONDITION::INFO,
+    &::loops::WHILE_LET_LOOP::INFO,
+    &::loops::WHILE_LET_ON_ITERATOR::INFO,
+    &::map_clone::MAP_CLONE::INFO,
+    &::matches::MATCH_AS_REF::INFO,
+    &::matches::MATCH_BOOL::INFO,
+    &::matches::MATCH_OVERLAPPING_ARM::INFO,
+    &::matches::MATCH_REF_PATS::INFO,
+    &::matches::MATCH_WILD_ERR_ARM::INFO,
+    &::matches::SINGLE_MATCH::INFO,
+    &::matches::SINGLE_MATCH_ELSE::INFO,
+    &::matches::WILDCARD_ENUM_MATCH_ARM::INFO,
+    &::mem_forget::MEM_FORGET::INFO,
+    &::methods::CHARS_LAST_CMP::INFO,
+    &::methods::CHARS_NEXT_CMP::INFO,
+    &::methods::CLONE_DOUBLE_REF::INFO,
+    &::methods::CLONE_ON_COPY::INFO,
+    &::methods::CLONE_ON_REF_PTR::INFO,
+    &::methods::FILTER_MAP::INFO,
+    &::methods::FILTER_NEXT::INFO,
+    &::methods::GET_UNWRAP::INFO,
+    &::methods::ITER_CLONED_COLLECT::INFO,
+    &::methods::ITER_NTH::INFO,
+    &::methods::ITER_SKIP_NEXT::INFO,
+    &::methods::NEW_RET_NO_SELF::INFO,
+    &::methods::OK_EXPECT::INFO,
+    &::methods::OPTION_MAP_OR_NONE::INFO,
+    &::methods::OPTION_MAP_UNWRAP_OR::INFO,
+    &::methods::OPTION_MAP_UNWRAP_OR_ELSE::INFO,
+    &::methods::OPTION_UNWRAP_USED::INFO,
+    &::methods::OR_FUN_CALL::INFO,
+    &::methods::RESULT_MAP_UNWRAP_OR_ELSE::INFO,
+    &::methods::RESULT_UNWRAP_USED::INFO,
+    &::methods::SEARCH_IS_SOME::INFO,
+    &::methods::SHOULD_IMPLEMENT_TRAIT::INFO,
+    &::methods::SINGLE_CHAR_PATTERN::INFO,
+    &::methods::STRING_EXTEND_CHARS::INFO,
+    &::methods::TEMPORARY_CSTRING_AS_PTR::INFO,
+    &::methods::UNNECESSARY_FOLD::INFO,
+    &::methods::USELESS_ASREF::INFO,
+    &::methods::WRONG_PUB_SELF_CONVENTION::INFO,
+    &::methods::WRONG_SELF_CONVENTION::INFO,
+    &::minmax::MIN_MAX::INFO,
+    &::misc::CMP_NAN::INFO,
+    &::misc::CMP_OWNED::INFO,
+    &::misc::FLOAT_CMP::INFO,
+    &::misc::FLOAT_CMP_CONST::INFO,
+    &::misc::MODULO_ONE::INFO,
+    &::misc::REDUNDANT_PATTERN::INFO,
+    &::misc::SHORT_CIRCUIT_STATEMENT::INFO,
+    &::misc::TOPLEVEL_REF_ARG::INFO,
+    &::misc::USED_UNDERSCORE_BINDING::INFO,
+    &::misc::ZERO_PTR::INFO,
+    &::misc_early::BUILTIN_TYPE_SHADOW::INFO,
+    &::misc_early::DOUBLE_NEG::INFO,
+    &::misc_early::DUPLICATE_UNDERSCORE_ARGUMENT::INFO,
+    &::misc_early::MIXED_CASE_HEX_LITERALS::INFO,
+    &::misc_early::REDUNDANT_CLOSURE_CALL::INFO,
+    &::misc_early::UNNEEDED_FIELD_PATTERN::INFO,
+    &::misc_early::UNSEPARATED_LITERAL_SUFFIX::INFO,
+    &::misc_early::ZERO_PREFIXED_LITERAL::INFO,
+    &::missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS::INFO,
+    &::mut_mut::MUT_MUT::INFO,
+    &::mut_reference::UNNECESSARY_MUT_PASSED::INFO,
+    &::mutex_atomic::MUTEX_ATOMIC::INFO,
+    &::mutex_atomic::MUTEX_INTEGER::INFO,
+    &::needless_bool::BOOL_COMPARISON::INFO,
+    &::needless_bool::NEEDLESS_BOOL::INFO,
+    &::needless_borrow::NEEDLESS_BORROW::INFO,
+    &::needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE::INFO,
+    &::needless_continue::NEEDLESS_CONTINUE::INFO,
+    &::needless_pass_by_value::NEEDLESS_PASS_BY_VALUE::INFO,
+    &::needless_update::NEEDLESS_UPDATE::INFO,
+    &::neg_multiply::NEG_MULTIPLY::INFO,
+    &::new_without_default::NEW_WITHOUT_DEFAULT::INFO,
+    &::new_without_default::NEW_WITHOUT_DEFAULT_DERIVE::INFO,
+    &::no_effect::NO_EFFECT::INFO,
+    &::no_effect::UNNECESSARY_OPERATION::INFO,
+    &::non_expressive_names::JUST_UNDERSCORES_AND_DIGITS::INFO,
+    &::non_expressive_names::MANY_SINGLE_CHAR_NAMES::INFO,
+    &::non_expressive_names::SIMILAR_NAMES::INFO,
+    &::ok_if_let::IF_LET_SOME_RESULT::INFO,
+    &::open_options::NONSENSICAL_OPEN_OPTIONS::INFO,
+    &::overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL::INFO,
+    &::panic::PANIC_PARAMS::INFO,
+    &::partialeq_ne_impl::PARTIALEQ_NE_IMPL::INFO,
+    &::precedence::PRECEDENCE::INFO,
+    &::ptr::CMP_NULL::INFO,
+    &::ptr::MUT_FROM_REF::INFO,
+    &::ptr::PTR_ARG::INFO,
+    &::question_mark::QUESTION_MARK::INFO,
+    &::ranges::ITERATOR_STEP_BY_ZERO::INFO,
+    &::ranges::RANGE_MINUS_ONE::INFO,
+    &::ranges::RANGE_PLUS_ONE::INFO,
+    &::ranges::RANGE_ZIP_WITH_LEN::INFO,
+    &::reachable_panic::UNDOCUMENTED_REACHABLE_PANIC::INFO,
+    &::redundant_clone::REDUNDANT_CLONE::INFO,
+    &::redundant_field_names::REDUNDANT_FIELD_NAMES::INFO,
+    &::reference::DEREF_ADDROF::INFO,
+    &::regex::INVALID_REGEX::INFO,
+    &::regex::REGEX_CAPTURE_INDEX_OUT_OF_BOUNDS::INFO,
+    &::regex::REGEX_CREATION_IN_LOOPS::INFO,
+    &::regex::REGEX_MACRO::INFO,
+    &::regex::TRIVIAL_REGEX::INFO,
+    &::replace_consts::REPLACE_CONSTS::INFO,
+    &::returns::LET_AND_RETURN::INFO,
+    &::returns::NEEDLESS_RETURN::INFO,
+    &::serde_api::SERDE_API_MISUSE::INFO,
+    &::shadow::SHADOW_REUSE::INFO,
+    &::shadow::SHADOW_SAME::INFO,
+    &::shadow::SHADOW_UNRELATED::INFO,
+    &::strings::STRING_ADD::INFO,
+    &::strings::STRING_ADD_ASSIGN::INFO,
+    &::strings::STRING_LIT_AS_BYTES::INFO,
+    &::suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL::INFO,
+    &::suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL::INFO,
+    &::swap::ALMOST_SWAPPED::INFO,
+    &::swap::MANUAL_SWAP::INFO,
+    &::temporary_assignment::TEMPORARY_ASSIGNMENT::INFO,
+    &::transmute::CROSSPOINTER_TRANSMUTE::INFO,
+    &::transmute::MISALIGNED_TRANSMUTE::INFO,
+    &::transmute::TRANSMUTE_BYTES_TO_STR::INFO,
+    &::transmute::TRANSMUTE_INT_TO_BOOL::INFO,
+    &::transmute::TRANSMUTE_INT_TO_CHAR::INFO,
+    &::transmute::TRANSMUTE_INT_TO_FLOAT::INFO,
+    &::transmute::TRANSMUTE_PTR_TO_REF::INFO,
+    &::transmute::USELESS_TRANSMUTE::INFO,
+    &::transmute::WRONG_TRANSMUTE::INFO,
+    &::types::ABSURD_EXTREME_COMPARISONS::INFO,
+    &::types::BORROWED_BOX::INFO,
+    &::types::BOX_VEC::INFO,
+    &::types::CAST_LOSSLESS::INFO,
+    &::types::CAST_POSSIBLE_TRUNCATION::INFO,
+    &::types::CAST_POSSIBLE_WRAP::INFO,
+    &::types::CAST_PRECISION_LOSS::INFO,
+    &::types::CAST_SIGN_LOSS::INFO,
+    &::types::CHAR_LIT_AS_U8::INFO,
+    &::types::IMPLICIT_HASHER::INFO,
+    &::types::INVALID_UPCAST_COMPARISONS::INFO,
+    &::types::LET_UNIT_VALUE::INFO,
+    &::types::LINKEDLIST::INFO,
+    &::types::OPTION_OPTION::INFO,
+    &::types::TYPE_COMPLEXITY::INFO,
+    &::types::UNIT_ARG::INFO,
+    &::types::UNIT_CMP::INFO,
+    &::types::UNNECESSARY_CAST::INFO,
+    &::unicode::NON_ASCII_LITERAL::INFO,
+    &::unicode::UNICODE_NOT_NFC::INFO,
+    &::unicode::ZERO_WIDTH_SPACE::INFO,
+    &::unsafe_blocks::OVERSIZED_UNSAFE_BLOCK::INFO,
+    &::unsafe_blocks::OVERSIZED_UNSAFE_FN::INFO,
+    &::unsafe_blocks::UNDOCUMENTED_UNSAFE_BLOCKS::INFO,
+    &::unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME::INFO,
+    &::unused_io_amount::UNUSED_IO_AMOUNT::INFO,
+    &::unused_label::UNUSED_LABEL::INFO,
+    &::use_self::USE_SELF::INFO,
+    &::utils::author::LINT_AUTHOR::INFO,
+    &::utils::inspector::DEEP_CODE_INSPECTION::INFO,
+    &::utils::internal_lints::CLIPPY_LINTS_INTERNAL::INFO,
+    &::utils::internal_lints::LINT_WITHOUT_LINT_PASS::INFO,
+    &::utils::unused_conf::UNKNOWN_CONFIGURATION_KEY::INFO,
+    &::utils::unused_conf::UNUSED_CONFIGURATION_KEY::INFO,
+    &::vec::USELESS_VEC::INFO,
+    &::write::PRINTLN_EMPTY_STRING::INFO,
+    &::write::PRINT_LITERAL::INFO,
+    &::write::PRINT_STDOUT::INFO,
+    &::write::PRINT_WITH_NEWLINE::INFO,
+    &::write::USE_DEBUG::INFO,
+    &::write::WRITELN_EMPTY_STRING::INFO,
+    &::write::WRITE_LITERAL::INFO,
+    &::write::WRITE_WITH_NEWLINE::INFO,
+    &::zero_div_zero::ZERO_DIVIDED_BY_ZERO::INFO,
+];
+
+/// Every lint declared with `declare_deprecated_lint!`, by name.
+pub static DEPRECATED: &[&DeprecatedLint] = &[
+    &::deprecated_lints::EXTEND_FROM_SLICE::INFO,
+    &::deprecated_lints::RANGE_STEP_BY_ZERO::INFO,
+    &::deprecated_lints::SHOULD_ASSERT_EQ::INFO,
+    &::deprecated_lints::STR_TO_STRING::INFO,
+    &::deprecated_lints::STRING_TO_STRING::INFO,
+    &::deprecated_lints::UNSTABLE_AS_MUT_SLICE::INFO,
+    &::deprecated_lints::UNSTABLE_AS_SLICE::INFO,
+];
+
+/// The lint groups registered by `register_plugins`, with the `declare_clippy_lint!` groups of
+/// their lints. The `internal_warn` lints are in no group.
+pub const GROUPS: &[(&str, &[&str])] = &[
+    ("clippy", &["correctness", "style", "complexity", "perf"]),
+    ("clippy_style", &["style"]),
+    ("clippy_complexity", &["complexity"]),
+    ("clippy_correctness", &["correctness"]),
+    ("clippy_perf", &["perf"]),
+    ("clippy_pedantic", &["pedantic"]),
+    ("clippy_restriction", &["restriction"]),
+    ("clippy_nursery", &["nursery"]),
+    ("clippy_internal", &["internal"]),
+];
+
+/// The lints declared with one of the `declare_clippy_lint!` groups `groups`.
+pub fn group_lints(groups: &[&str]) -> Vec<&'static Lint> {
+    LINTS
+        .iter()
+        .filter(|lint| groups.contains(&lint.group))
+        .map(|lint| *lint.lint)
+        .collect()
+}
+
+/// The lints shown in the lint list and counted in the README, ie. not the internal ones.
+pub fn public_lints() -> impl Iterator<Item = &'static LintMetadata> {
+    LINTS
+        .iter()
+        .cloned()
+        .filter(|lint| lint.group != "internal" && lint.group != "internal_warn")
+}
+
+impl LintMetadata {
+    /// The name of the lint, in lowercase.
+    pub fn name(&self) -> String {
+        self.lint.name_lower()
+    }
+
+    /// The level of the lint when neither attributes nor flags change it.
+    pub fn default_level(&self) -> &'static str {
+        self.lint.default_level.as_str()
+    }
+
+    /// The one line description.
+    pub fn description(&self) -> &'static str {
+        self.lint.desc
+    }
+
+    /// The lint groups of `GROUPS` containing this lint, eg. `clippy` and `clippy_style`.
+    pub fn groups(&self) -> Vec<&'static str> {
+        GROUPS
+            .iter()
+            .filter(|&&(_, groups)| groups.contains(&self.group))
+            .map(|&(name, _)| name)
+            .collect()
+    }
+
+    /// The documentation of the lint, without the space after the `///` of each line.
+    pub fn docs(&self) -> String {
+        self.docs
+            .iter()
+            .map(|&line| if line.starts_with(' ') { &line[1..] } else { line })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The configuration keys affecting this lint.
+    pub fn conf_keys(&self) -> Vec<&'static ConfKey> {
+        let name = self.name();
+        KEYS.iter()
+            .filter(|key| key.lints().contains(&name))
+            .collect()
+    }
+
+    /// The full explanation of the lint, as shown by `cargo clippy --explain`.
+    pub fn explain(&self) -> String {
+        let mut text = String::new();
+        let _ = writeln!(text, "{}: {}\n", self.name(), self.description());
+        let groups = self.groups()
+            .iter()
+            .map(|group| format!("`{}`", group))
+            .collect::<Vec<_>>();
+        let _ = writeln!(
+            text,
+            "groups: {}, default level: {}\n",
+            if groups.is_empty() { "none".to_owned() } else { groups.join(", ") },
+            self.default_level()
+        );
+        let _ = writeln!(text, "{}", self.docs());
+
+        let keys = self.conf_keys();
+        if !keys.is_empty() {
+            let _ = writeln!(text, "\n**Configuration (`clippy.toml`):**\n");
+            for key in keys {
+                let _ = writeln!(text, "* `{} = {}`: {}", key.kebab_name(), key.default, key.description());
+            }
+        }
+        text
+    }
+}
+
+/// Find a lint by name, ignoring the case and accepting `-` for `_`.
+pub fn find(name: &str) -> Option<&'static LintMetadata> {
+    let name = name.to_lowercase().replace('-', "_");
+    LINTS.iter().cloned().find(|lint| lint.name() == name)
+}
+
+/// The lint with the name closest to `name`, to suggest when `name` is unknown.
+pub fn suggest(name: &str) -> Option<&'static LintMetadata> {
+    let name = name.to_lowercase().replace('-', "_");
+    LINTS
+        .iter()
+        .cloned()
+        .map(|lint| (lev_distance(&name, &lint.name()), lint))
+        .filter(|&(distance, _)| distance <= ::std::cmp::max(name.len(), 3) / 3)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, lint)| lint)
+}
@@ -0,0 +1,127 @@
+//! Support for `cargo clippy --metrics`: the complexity and size of every function and item, as
+//! computed by some lints, and not only when they cross a threshold.
+//!
+//! `cargo clippy` passes a directory to the driver in the `CLIPPY_METRICS` environment variable.
+//! Each driver process appends its measures to a file there, one per line as
+//! `metric<TAB>file<TAB>line<TAB>item<TAB>value`, and `cargo clippy` merges them into the report.
+
+use rustc::hir::map::Node;
+use rustc::lint::{LateContext, LintContext};
+use rustc::session::Session;
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Mutex;
+use syntax::ast::NodeId;
+use utils::baseline;
+
+/// A measure of an item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    /// The cyclomatic complexity of a function, see `CYCLOMATIC_COMPLEXITY`.
+    CyclomaticComplexity,
+    /// The cognitive complexity of a function, see `COGNITIVE_COMPLEXITY`.
+    CognitiveComplexity,
+    /// The number of arguments of a function, see `TOO_MANY_ARGUMENTS`.
+    Arguments,
+    /// The number of lines of a function.
+    Lines,
+    /// The complexity of the most complex type written in an item, see `TYPE_COMPLEXITY`.
+    TypeComplexity,
+}
+
+impl Metric {
+    /// The name of the metric in the report.
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::CyclomaticComplexity => "cyclomatic_complexity",
+            Metric::CognitiveComplexity => "cognitive_complexity",
+            Metric::Arguments => "arguments",
+            Metric::Lines => "lines",
+            Metric::TypeComplexity => "type_complexity",
+        }
+    }
+}
+
+lazy_static! {
+    /// The file the measures of this process are appended to, if `CLIPPY_METRICS` is set and
+    /// recording did not fail.
+    static ref FILE: Mutex<Option<PathBuf>> = Mutex::new(
+        env::var_os("CLIPPY_METRICS").map(|dir| Path::new(&dir).join(format!("{}.txt", process::id())))
+    );
+}
+
+/// Called when the lints are registered. Create the file of this process even if it records
+/// nothing, so `cargo clippy` can tell that the crate was checked.
+pub fn start(sess: &Session) {
+    let mut file = FILE.lock().expect("no threading -> mutex always safe");
+    let failed = match *file {
+        Some(ref path) => match fs::OpenOptions::new().create(true).append(true).open(path) {
+            Ok(_) => false,
+            Err(err) => {
+                sess.err(&format!("could not record metrics in `{}`: {}", path.display(), err));
+                true
+            },
+        },
+        None => false,
+    };
+    if failed {
+        *file = None;
+    }
+}
+
+/// Record a measure of the item `node_id`: a function, a type or a constant, either free or in a
+/// trait or an impl. The measures of other nodes, like closures, are ignored. When an item is
+/// measured several times, the report keeps the largest value.
+pub fn record(cx: &LateContext, node_id: NodeId, metric: Metric, value: u64) {
+    let mut file = FILE.lock().expect("no threading -> mutex always safe");
+    let failed = match *file {
+        Some(ref path) => {
+            match cx.tcx.hir.find(node_id) {
+                Some(Node::NodeItem(_)) | Some(Node::NodeTraitItem(_)) | Some(Node::NodeImplItem(_)) => (),
+                _ => return,
+            }
+
+            let span = cx.tcx.hir.span(node_id);
+            let codemap = cx.sess().codemap();
+            let line = format!(
+                "{}\t{}\t{}\t{}\t{}",
+                metric.name(),
+                baseline::file_name(codemap, span),
+                codemap.lookup_char_pos(span.lo()).line,
+                cx.tcx.item_path_str(cx.tcx.hir.local_def_id(node_id)),
+                value
+            );
+            let written = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| writeln!(file, "{}", line));
+            match written {
+                Ok(()) => false,
+                Err(err) => {
+                    cx.sess().err(&format!("could not record metrics in `{}`: {}", path.display(), err));
+                    true
+                },
+            }
+        },
+        None => false,
+    };
+    // the error is only reported once
+    if failed {
+        *file = None;
+    }
+}
+
+/// Record the number of lines of the item `node_id`.
+pub fn record_lines(cx: &LateContext, node_id: NodeId) {
+    if FILE.lock().expect("no threading -> mutex always safe").is_none() {
+        return;
+    }
+    let span = cx.tcx.hir.span(node_id);
+    let codemap = cx.sess().codemap();
+    let lines = codemap.lookup_char_pos(span.hi()).line - codemap.lookup_char_pos(span.lo()).line + 1;
+    record(cx, node_id, Metric::Lines, lines as u64);
+}
@@ -0,0 +1,279 @@
+//! Helpers for the lints working on the MIR of the functions: the moves, borrows and uses of the
+//! locals are explicit there, instead of being rebuilt from the HIR with `ExprUseVisitor`.
+//!
+//! A lint gets the MIR of the function it checks in `LateLintPass::check_fn` with `fn_mir`.
+
+use rustc::lint::LateContext;
+use rustc::mir::{BasicBlock, BorrowKind, Local, Location, Mir, Place, ProjectionElem, TerminatorKind, START_BLOCK};
+use rustc::mir::visit::{PlaceContext, Visitor};
+use rustc::ty::{self, Ty};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::Deref;
+use syntax::ast::NodeId;
+
+/// The MIR of the function, method or closure `node_id`, as checked by the borrow checker.
+///
+/// This is the MIR before the optimizations, which may remove temporaries or propagate copies,
+/// and before the drops are elaborated. The optimizations steal it, which only happens that early
+/// for `const fn`s evaluated in constants, so `None` is returned for those.
+pub fn fn_mir<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, node_id: NodeId) -> Option<impl Deref<Target = Mir<'tcx>> + 'tcx> {
+    let def_id = cx.tcx.hir.local_def_id(node_id);
+    if cx.tcx.is_const_fn(def_id) {
+        return None;
+    }
+    Some(cx.tcx.mir_validated(def_id).borrow())
+}
+
+/// How a local is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UseKind {
+    /// Assigned, including as the destination of a call.
+    Assign,
+    /// Read or copied.
+    Read,
+    Move,
+    Borrow {
+        mutable: bool,
+    },
+    Drop,
+}
+
+/// A use of a local, or of a field or a dereference of it.
+#[derive(Copy, Clone, Debug)]
+pub struct LocalUse {
+    pub local: Local,
+    pub location: Location,
+    pub kind: UseKind,
+    /// Whether the whole local is used, and not a projection of it.
+    pub whole: bool,
+}
+
+/// The uses of the locals in the basic block `bb`. The storage markers are not uses.
+pub fn block_uses(mir: &Mir, bb: BasicBlock) -> Vec<LocalUse> {
+    let mut collector = UseCollector::default();
+    collector.visit_basic_block_data(bb, &mir[bb]);
+    collector.uses
+}
+
+/// The uses of the locals at each statement and terminator of `mir`.
+pub fn uses_by_location(mir: &Mir) -> Vec<(Location, Vec<LocalUse>)> {
+    let mut uses = Vec::new();
+    for (bb, data) in mir.basic_blocks().iter_enumerated() {
+        for (index, stmt) in data.statements.iter().enumerate() {
+            let location = Location {
+                block: bb,
+                statement_index: index,
+            };
+            let mut collector = UseCollector::default();
+            collector.visit_statement(bb, stmt, location);
+            uses.push((location, collector.uses));
+        }
+        if let Some(ref terminator) = data.terminator {
+            let location = Location {
+                block: bb,
+                statement_index: data.statements.len(),
+            };
+            let mut collector = UseCollector::default();
+            collector.visit_terminator(bb, terminator, location);
+            uses.push((location, collector.uses));
+        }
+    }
+    uses
+}
+
+/// The base local of a place, `x` for `(*x).field`. Statics have none.
+pub fn base_local(place: &Place) -> Option<Local> {
+    match *place {
+        Place::Local(local) => Some(local),
+        Place::Static(_) => None,
+        Place::Projection(ref projection) => base_local(&projection.base),
+    }
+}
+
+/// The basic blocks reachable from `start`, including `start`, through the normal and the
+/// unwinding edges.
+pub fn reachable_blocks(mir: &Mir, start: BasicBlock) -> Vec<BasicBlock> {
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    let mut blocks = Vec::new();
+    seen.insert(start);
+    queue.push_back(start);
+    while let Some(bb) = queue.pop_front() {
+        blocks.push(bb);
+        for &succ in mir[bb].terminator().successors().iter() {
+            if seen.insert(succ) {
+                queue.push_back(succ);
+            }
+        }
+    }
+    blocks
+}
+
+/// Returns true if one of `locals` may be used from the start of the basic block `start`, ie. is
+/// live there. Dropping them is not a use.
+pub fn is_used_from(mir: &Mir, start: BasicBlock, locals: &HashSet<Local>) -> bool {
+    reachable_blocks(mir, start).into_iter().any(|bb| {
+        block_uses(mir, bb)
+            .iter()
+            .any(|u| u.kind != UseKind::Drop && locals.contains(&u.local))
+    })
+}
+
+/// Updates `init`, the locals which may hold a value, with the `uses` at a location: assigning the
+/// whole local initializes it, moving a part of it or dropping it ends its value.
+pub fn update_initialized(init: &mut HashSet<Local>, uses: &[LocalUse]) {
+    for u in uses {
+        match u.kind {
+            UseKind::Assign => if u.whole {
+                init.insert(u.local);
+            },
+            UseKind::Move | UseKind::Drop => {
+                init.remove(&u.local);
+            },
+            UseKind::Read | UseKind::Borrow { .. } => (),
+        }
+    }
+}
+
+/// The locals which may hold a value at the start of each basic block: the arguments, and the
+/// locals assigned on a path from the start of the function and not moved or dropped since.
+pub fn maybe_initialized(mir: &Mir) -> HashMap<BasicBlock, HashSet<Local>> {
+    let mut blocks: HashMap<BasicBlock, Vec<Vec<LocalUse>>> = HashMap::new();
+    for (location, uses) in uses_by_location(mir) {
+        blocks.entry(location.block).or_insert_with(Vec::new).push(uses);
+    }
+
+    let mut entry: HashMap<BasicBlock, HashSet<Local>> = HashMap::new();
+    entry.insert(START_BLOCK, mir.args_iter().collect());
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (bb, data) in mir.basic_blocks().iter_enumerated() {
+            let mut init = match entry.get(&bb) {
+                Some(init) => init.clone(),
+                None => continue,
+            };
+            for uses in blocks.get(&bb).map_or(&[][..], |uses| &uses[..]) {
+                update_initialized(&mut init, uses);
+            }
+            for &succ in data.terminator().successors().iter() {
+                let succ_init = entry.entry(succ).or_insert_with(HashSet::new);
+                if !succ_init.is_superset(&init) {
+                    succ_init.extend(init.iter().cloned());
+                    changed = true;
+                }
+            }
+        }
+    }
+    entry
+}
+
+/// Returns true if a value of type `ty` may hold a borrow.
+pub fn may_borrow(ty: Ty) -> bool {
+    ty.walk().any(|ty| match ty.sty {
+        ty::TyRef(..) | ty::TyRawPtr(..) | ty::TyDynamic(..) | ty::TyClosure(..) | ty::TyGenerator(..) |
+        ty::TyParam(..) => true,
+        ty::TyAdt(_, substs) => substs.regions().next().is_some(),
+        _ => false,
+    })
+}
+
+/// The locals which may hold a borrow of `local` or of a part of it: the borrows, except the one
+/// at `except`, and the locals assigned from them, directly or through a call, if their type can
+/// hold a borrow.
+pub fn borrows_of(mir: &Mir, local: Local, except: Option<Location>) -> HashSet<Local> {
+    let locations = uses_by_location(mir);
+    let assigned = |uses: &[LocalUse]| {
+        uses.iter()
+            .filter(|u| u.kind == UseKind::Assign && may_borrow(mir.local_decls[u.local].ty))
+            .map(|u| u.local)
+            .collect::<Vec<_>>()
+    };
+
+    let mut borrows = HashSet::new();
+    for &(location, ref uses) in &locations {
+        let is_borrow = |u: &LocalUse| match u.kind {
+            UseKind::Borrow { .. } => u.local == local,
+            _ => false,
+        };
+        if Some(location) != except && uses.iter().any(is_borrow) {
+            borrows.extend(assigned(uses));
+        }
+    }
+
+    loop {
+        let len = borrows.len();
+        for &(_, ref uses) in &locations {
+            let reads_borrow = uses.iter().any(|u| {
+                u.kind != UseKind::Assign && u.kind != UseKind::Drop && borrows.contains(&u.local)
+            });
+            if reads_borrow {
+                borrows.extend(assigned(uses));
+            }
+        }
+        if borrows.len() == len {
+            return borrows;
+        }
+    }
+}
+
+#[derive(Default)]
+struct UseCollector {
+    uses: Vec<LocalUse>,
+}
+
+impl<'tcx> Visitor<'tcx> for UseCollector {
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext<'tcx>, location: Location) {
+        let kind = match context {
+            PlaceContext::Store | PlaceContext::AsmOutput | PlaceContext::Call => UseKind::Assign,
+            PlaceContext::Move => UseKind::Move,
+            PlaceContext::Borrow { kind, .. } => UseKind::Borrow {
+                mutable: match kind {
+                    BorrowKind::Mut { .. } => true,
+                    _ => false,
+                },
+            },
+            PlaceContext::Drop => UseKind::Drop,
+            PlaceContext::StorageLive | PlaceContext::StorageDead | PlaceContext::Validate => return,
+            _ => UseKind::Read,
+        };
+
+        let mut place = place;
+        let mut whole = true;
+        loop {
+            match *place {
+                Place::Local(local) => {
+                    self.uses.push(LocalUse {
+                        local,
+                        location,
+                        kind,
+                        whole,
+                    });
+                    return;
+                },
+                Place::Static(_) => return,
+                Place::Projection(ref projection) => {
+                    // the index of `x[i]` is read
+                    if let ProjectionElem::Index(index) = projection.elem {
+                        self.uses.push(LocalUse {
+                            local: index,
+                            location,
+                            kind: UseKind::Read,
+                            whole: true,
+                        });
+                    }
+                    whole = false;
+                    place = &projection.base;
+                },
+            }
+        }
+    }
+    fn visit_terminator_kind(&mut self, block: BasicBlock, kind: &TerminatorKind<'tcx>, location: Location) {
+        self.super_terminator_kind(block, kind, location);
+        // before the drops are elaborated, assigning a value with a destructor drops the old value
+        // and stores the new one in a single terminator
+        if let TerminatorKind::DropAndReplace { location: ref place, .. } = *kind {
+            self.visit_place(place, PlaceContext::Store, location);
+        }
+    }
+}
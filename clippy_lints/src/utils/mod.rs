@@ -1,7 +1,7 @@
 use reexport::*;
 use rustc::hir;
 use rustc::hir::*;
-use rustc::hir::def_id::{DefId, CRATE_DEF_INDEX};
+use rustc::hir::def_id::{CrateNum, DefId, CRATE_DEF_INDEX, LOCAL_CRATE};
 use rustc::hir::def::Def;
 use rustc::hir::intravisit::{NestedVisitorMap, Visitor};
 use rustc::hir::map::Node;
@@ -17,20 +17,26 @@ use std::str::FromStr;
 use std::rc::Rc;
 use syntax::ast::{self, LitKind};
 use syntax::attr;
-use syntax::codemap::{CompilerDesugaringKind, ExpnFormat, ExpnInfo, Span, DUMMY_SP};
+use syntax::codemap::{BytePos, CompilerDesugaringKind, ExpnFormat, ExpnInfo, Span, DUMMY_SP};
 use syntax::errors::DiagnosticBuilder;
 use syntax::ptr::P;
 use syntax::symbol::keywords;
 
+pub mod baseline;
 pub mod comparisons;
 pub mod conf;
 pub mod constants;
 mod hir_utils;
 pub mod paths;
 pub mod sugg;
+pub mod unused_conf;
 pub mod inspector;
 pub mod internal_lints;
+pub mod levels;
+pub mod metadata;
+pub mod metrics;
 pub mod author;
+pub mod mir;
 pub mod ptr;
 pub use self::hir_utils::{SpanlessEq, SpanlessHash};
 
@@ -408,6 +414,16 @@ pub fn snippet<'a, 'b, T: LintContext<'b>>(cx: &T, span: Span, default: &'a str)
     snippet_opt(cx, span).map_or_else(|| Cow::Borrowed(default), From::from)
 }
 
+/// The span of the signature of an item like a function or a trait, without its
+/// body: up to its first `{`.
+#[allow(cast_possible_truncation)]
+pub fn item_header_span<'a, T: LintContext<'a>>(cx: &T, span: Span) -> Span {
+    match snippet_opt(cx, span).and_then(|snippet| snippet.find('{').map(|i| snippet[..i].trim_right().len())) {
+        Some(len) => span.with_hi(span.lo() + BytePos(len as u32)),
+        None => span,
+    }
+}
+
 /// Convert a span to a code snippet. Returns `None` if not available.
 pub fn snippet_opt<'a, T: LintContext<'a>>(cx: &T, span: Span) -> Option<String> {
     cx.sess().codemap().span_to_snippet(span).ok()
@@ -550,19 +566,34 @@ impl<'a> Drop for DiagnosticWrapper<'a> {
 }
 
 impl<'a> DiagnosticWrapper<'a> {
+    /// Start the diagnostic of a lint, it is cancelled if the finding is in the baseline given to
+    /// `cargo clippy --baseline`.
+    fn new<'tcx, T: LintContext<'tcx>>(cx: &'a T, lint: &'static Lint, sp: Span, msg: &str) -> Self {
+        let mut db = cx.struct_span_lint(lint, sp, msg);
+        if !db.cancelled() && baseline::is_known(cx.sess(), lint, sp) {
+            db.cancel();
+        }
+        DiagnosticWrapper(db)
+    }
+
     fn docs_link(&mut self, lint: &'static Lint) {
         if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
-            self.0.help(&format!(
-                "for further information visit https://rust-lang-nursery.github.io/rust-clippy/v{}/index.html#{}",
-                env!("CARGO_PKG_VERSION"),
-                lint.name_lower()
-            ));
+            self.0.help(&format!("for further information visit {}", docs_url(&lint.name_lower())));
         }
     }
 }
 
+/// The URL of the documentation of a lint, for this version of clippy.
+pub fn docs_url(lint: &str) -> String {
+    format!(
+        "https://rust-lang-nursery.github.io/rust-clippy/v{}/index.html#{}",
+        env!("CARGO_PKG_VERSION"),
+        lint
+    )
+}
+
 pub fn span_lint<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, sp: Span, msg: &str) {
-    DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg)).docs_link(lint);
+    DiagnosticWrapper::new(cx, lint, sp, msg).docs_link(lint);
 }
 
 pub fn span_help_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
@@ -572,7 +603,7 @@ pub fn span_help_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     msg: &str,
     help: &str,
 ) {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
+    let mut db = DiagnosticWrapper::new(cx, lint, span, msg);
     db.0.help(help);
     db.docs_link(lint);
 }
@@ -585,7 +616,7 @@ pub fn span_note_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     note_span: Span,
     note: &str,
 ) {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
+    let mut db = DiagnosticWrapper::new(cx, lint, span, msg);
     if note_span == span {
         db.0.note(note);
     } else {
@@ -603,11 +634,34 @@ pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
 ) where
     F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
 {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg));
+    let mut db = DiagnosticWrapper::new(cx, lint, sp, msg);
     f(&mut db.0);
     db.docs_link(lint);
 }
 
+/// How confident Clippy is that a suggestion does what the user intended.
+///
+/// This is forwarded to the JSON diagnostics so tools like `cargo clippy --fix` and rustfix can
+/// decide which suggestions to apply without asking the user.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended and can be applied automatically.
+    MachineApplicable,
+    /// The suggestion may be what the user intended, but it is uncertain. For example it may
+    /// change the semantics of the code or come from a heuristic.
+    MaybeIncorrect,
+    /// The suggestion contains placeholders like `..` or `_` that the user has to fill in.
+    HasPlaceholders,
+}
+
+impl Applicability {
+    /// rustc only knows whether a suggestion is approximate, anything that is not
+    /// machine-applicable needs a human to look at it.
+    fn is_approximate(self) -> bool {
+        self != Applicability::MachineApplicable
+    }
+}
+
 /// Add a span lint with a suggestion on how to fix it.
 ///
 /// These suggestions can be parsed by rustfix to allow it to automatically fix your code.
@@ -629,9 +683,10 @@ pub fn span_lint_and_sugg<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     msg: &str,
     help: &str,
     sugg: String,
+    applicability: Applicability,
 ) {
     span_lint_and_then(cx, lint, sp, msg, |db| {
-        db.span_suggestion(sp, help, sugg);
+        db.span_suggestion_with_applicability(sp, help, sugg, applicability);
     });
 }
 
@@ -641,7 +696,7 @@ pub fn span_lint_and_sugg<'a, 'tcx: 'a, T: LintContext<'tcx>>(
 /// appear once per
 /// replacement. In human-readable format though, it only appears once before
 /// the whole suggestion.
-pub fn multispan_sugg<I>(db: &mut DiagnosticBuilder, help_msg: String, sugg: I)
+pub fn multispan_sugg<I>(db: &mut DiagnosticBuilder, help_msg: String, sugg: I, applicability: Applicability)
 where
     I: IntoIterator<Item = (Span, String)>,
 {
@@ -660,11 +715,91 @@ where
         ],
         msg: help_msg,
         show_code_when_inline: true,
-        approximate: false,
+        approximate: applicability.is_approximate(),
     };
     db.suggestions.push(sugg);
 }
 
+/// Suggestion methods of `DiagnosticBuilder` that record an `Applicability`.
+///
+/// Use these instead of the plain `span_suggestion*` methods, so every suggestion Clippy emits
+/// says whether it is safe to apply automatically.
+pub trait DiagnosticBuilderApplicabilityExt {
+    /// Like `DiagnosticBuilder::span_suggestion`.
+    fn span_suggestion_with_applicability(
+        &mut self,
+        sp: Span,
+        msg: &str,
+        suggestion: String,
+        applicability: Applicability,
+    ) -> &mut Self;
+
+    /// Like `DiagnosticBuilder::span_suggestion_short`, the suggested code is not shown inline.
+    fn span_suggestion_short_with_applicability(
+        &mut self,
+        sp: Span,
+        msg: &str,
+        suggestion: String,
+        applicability: Applicability,
+    ) -> &mut Self;
+
+    /// Like `DiagnosticBuilder::span_suggestions`, several alternative replacements of one span.
+    fn span_suggestions_with_applicability(
+        &mut self,
+        sp: Span,
+        msg: &str,
+        suggestions: Vec<String>,
+        applicability: Applicability,
+    ) -> &mut Self;
+}
+
+impl<'a> DiagnosticBuilderApplicabilityExt for DiagnosticBuilder<'a> {
+    fn span_suggestion_with_applicability(
+        &mut self,
+        sp: Span,
+        msg: &str,
+        suggestion: String,
+        applicability: Applicability,
+    ) -> &mut Self {
+        self.span_suggestions_with_applicability(sp, msg, vec![suggestion], applicability)
+    }
+
+    fn span_suggestion_short_with_applicability(
+        &mut self,
+        sp: Span,
+        msg: &str,
+        suggestion: String,
+        applicability: Applicability,
+    ) -> &mut Self {
+        self.span_suggestion_with_applicability(sp, msg, suggestion, applicability);
+        if let Some(sugg) = self.suggestions.last_mut() {
+            sugg.show_code_when_inline = false;
+        }
+        self
+    }
+
+    fn span_suggestions_with_applicability(
+        &mut self,
+        sp: Span,
+        msg: &str,
+        suggestions: Vec<String>,
+        applicability: Applicability,
+    ) -> &mut Self {
+        self.suggestions.push(rustc_errors::CodeSuggestion {
+            substitutions: suggestions
+                .into_iter()
+                .map(|snippet| rustc_errors::Substitution {
+                    parts: vec![rustc_errors::SubstitutionPart { snippet, span: sp }],
+                })
+                .collect(),
+            msg: msg.to_owned(),
+            show_code_when_inline: true,
+            approximate: applicability.is_approximate(),
+        });
+        self
+    }
+}
+
 /// Return the base type for HIR references and pointers.
 pub fn walk_ptrs_hir_ty(ty: &hir::Ty) -> &hir::Ty {
     match ty.node {
@@ -727,6 +862,10 @@ impl LimitStack {
             .last()
             .expect("there should always be a value in the stack")
     }
+    /// Whether the initial limit has been replaced by an attribute.
+    pub fn is_overridden(&self) -> bool {
+        self.stack.len() > 1
+    }
     pub fn push_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
         let stack = &mut self.stack;
         parse_attrs(sess, attrs, name, |val| stack.push(val));
@@ -773,6 +912,45 @@ pub fn is_expn_of(mut span: Span, name: &str) -> Option<Span> {
     }
 }
 
+/// Return the pre-expansion span and the name of the outermost macro of `macros`
+/// this span comes from. The macros are given by path, eg. `log::info`: the last
+/// segment is compared with the name of the macro and, if there are several
+/// segments, the first one with the crate defining the macro (`crate` for the
+/// current crate). Exported `macro_rules!` macros live at the root of their crate,
+/// so that is their full path.
+pub fn is_expn_of_any<'a>(cx: &LateContext, mut span: Span, macros: &'a [String]) -> Option<(Span, &'a str)> {
+    let mut found = None;
+    while let Some(info) = span.ctxt().outer().expn_info() {
+        let mac_name = info.callee.name();
+        let found_here = macros.iter().find(|path| {
+            let name = path.rsplit("::").next().unwrap_or(path);
+            mac_name == name && path.find("::").map_or(true, |end| is_macro_of_crate(cx, &info, &path[..end]))
+        });
+        if let Some(path) = found_here {
+            found = Some((info.call_site, path.rsplit("::").next().unwrap_or(path)));
+        }
+        span = info.call_site;
+    }
+    found
+}
+
+/// Returns true if the macro of an expansion is defined in the crate `krate`,
+/// `crate` being the current crate. The crate is the one the file of the
+/// definition comes from.
+fn is_macro_of_crate(cx: &LateContext, info: &ExpnInfo, krate: &str) -> bool {
+    let def_site = match info.callee.span {
+        Some(span) => span,
+        None => return false,
+    };
+    let file = cx.sess().codemap().lookup_char_pos(def_site.lo()).file;
+    let cnum = CrateNum::from_u32(file.crate_of_origin);
+    if krate == "crate" {
+        cnum == LOCAL_CRATE
+    } else {
+        cx.tcx.crate_name(cnum) == krate
+    }
+}
+
 /// Return the pre-expansion span if is this directly comes from an expansion
 /// of the macro `name`.
 /// The difference with `is_expn_of` is that in
@@ -0,0 +1,123 @@
+//! Lint the keys of the configuration file that are unknown or configure lints which are allowed
+//! anyway.
+
+use rustc::hir;
+use rustc::lint::*;
+use std::path::PathBuf;
+use syntax::ast::CRATE_NODE_ID;
+use syntax::codemap::{Span, DUMMY_SP};
+use utils::conf::{key_span, Conf, KeyPath, KEYS};
+use utils::{span_lint, span_lint_and_then};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for keys of the configuration file that Clippy does
+    /// not know.
+    ///
+    /// **Why is this bad?** The key is ignored, this is usually a typo and the
+    /// configuration does not do what it is meant to.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// cyclomatic-complexity-treshold = 30
+    /// ```
+    pub UNKNOWN_CONFIGURATION_KEY,
+    correctness,
+    "unknown key in Clippy's configuration file"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for keys of the configuration file configuring
+    /// lints which are allowed at the crate root.
+    ///
+    /// **Why is this bad?** The key has no effect, it is probably left over.
+    ///
+    /// **Known problems:** Only the level at the crate root is checked, a lint
+    /// enabled in a module still uses the key.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// too-many-arguments-threshold = 10
+    /// ```
+    /// with `#![allow(too_many_arguments)]` in the crate root.
+    pub UNUSED_CONFIGURATION_KEY,
+    style,
+    "key of Clippy's configuration file configuring allowed lints"
+}
+
+pub struct UnusedConf {
+    /// The path of the configuration file.
+    path: Option<PathBuf>,
+    /// The top-level keys set in the configuration file.
+    keys: Vec<String>,
+    /// The unknown keys with the closest known key.
+    unknown_keys: Vec<(KeyPath, Option<String>)>,
+    /// Every lint registered by Clippy.
+    lints: Vec<&'static Lint>,
+}
+
+impl UnusedConf {
+    pub fn new(conf: &Conf, lints: Vec<&'static Lint>) -> Self {
+        Self {
+            path: conf.path.clone(),
+            keys: conf.keys.clone(),
+            unknown_keys: conf.unknown_keys.clone(),
+            lints,
+        }
+    }
+
+    /// The span of `key` in the configuration file, or a dummy span if it is not found.
+    fn span(&self, cx: &LateContext, key: &KeyPath) -> Span {
+        self.path
+            .as_ref()
+            .and_then(|path| key_span(cx.sess().codemap(), path, key))
+            .unwrap_or(DUMMY_SP)
+    }
+}
+
+impl LintPass for UnusedConf {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNKNOWN_CONFIGURATION_KEY, UNUSED_CONFIGURATION_KEY)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedConf {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx hir::Crate) {
+        for &(ref key, ref suggestion) in &self.unknown_keys {
+            let msg = format!("unknown key `{}` in Clippy's configuration file", key);
+            span_lint_and_then(cx, UNKNOWN_CONFIGURATION_KEY, self.span(cx, key), &msg, |db| {
+                if let Some(ref suggestion) = *suggestion {
+                    db.help(&format!("did you mean `{}`?", suggestion));
+                }
+            });
+        }
+
+        for key in KEYS {
+            let name = key.kebab_name();
+            if !self.keys.contains(&name) {
+                continue;
+            }
+            let names = key.lints();
+            let lints = self.lints
+                .iter()
+                .filter(|lint| names.contains(&lint.name_lower()))
+                .collect::<Vec<_>>();
+            // only the level at the crate root is checked, the lint may still be enabled in a module
+            let allowed = !lints.is_empty()
+                && lints
+                    .iter()
+                    .all(|&&lint| cx.tcx.lint_level_at_node(lint, CRATE_NODE_ID).0 == Level::Allow);
+            if !allowed {
+                continue;
+            }
+
+            let msg = format!(
+                "`{}` in Clippy's configuration file has no effect because `{}` is allowed",
+                name,
+                names.join("`, `")
+            );
+            span_lint(cx, UNUSED_CONFIGURATION_KEY, self.span(cx, &KeyPath::top(name)), &msg);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Support for `cargo clippy --write-baseline`: merge the findings recorded by the driver
+//! processes into the baseline file.
+//!
+//! The format of the baseline file is described in `clippy_lints/src/utils/baseline.rs`.
+
+use serde_json;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The baseline file used when `--baseline` is not given.
+pub const DEFAULT_FILE: &str = "clippy.baseline";
+
+/// A directory the driver processes record their findings in, removed when dropped.
+pub struct RecordDir(PathBuf);
+
+impl RecordDir {
+    pub fn new() -> io::Result<Self> {
+        let dir = ::std::env::temp_dir().join(format!("clippy-baseline-{}", ::std::process::id()));
+        fs::create_dir_all(&dir)?;
+        Ok(RecordDir(dir))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Merge the recorded findings and write them to `baseline`. Returns the number of findings,
+    /// or `None` without touching `baseline` if no driver process ran.
+    pub fn write_baseline(&self, baseline: &Path) -> io::Result<Option<usize>> {
+        let mut records = Vec::new();
+        for entry in fs::read_dir(&self.0)? {
+            let mut content = String::new();
+            fs::File::open(entry?.path())?.read_to_string(&mut content)?;
+            records.push(content);
+        }
+        if records.is_empty() {
+            return Ok(None);
+        }
+
+        let (content, count) = merge(records.iter().map(String::as_str));
+        fs::File::create(baseline)?.write_all(content.as_bytes())?;
+        Ok(Some(count))
+    }
+}
+
+/// Merge the findings recorded by the driver processes, given the content of their files, into
+/// the content of a baseline file. Returns it with the number of findings.
+///
+/// The same file can be checked by several processes, eg. once as a library and once as a test,
+/// so a finding is kept as many times as a single process reported it.
+pub fn merge<'a, I: IntoIterator<Item = &'a str>>(records: I) -> (String, usize) {
+    let mut findings: BTreeMap<&str, usize> = BTreeMap::new();
+    for record in records {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for line in record.lines().filter(|line| !line.is_empty()) {
+            *counts.entry(line).or_insert(0) += 1;
+        }
+        for (line, count) in counts {
+            let total = findings.entry(line).or_insert(0);
+            *total = (*total).max(count);
+        }
+    }
+
+    let mut content = "# Clippy baseline, written by `cargo clippy --write-baseline`\n".to_owned();
+    for (line, &count) in &findings {
+        for _ in 0..count {
+            content.push_str(line);
+            content.push('\n');
+        }
+    }
+    (content, findings.values().sum())
+}
+
+/// Make `cargo` check every target of the workspace again: the findings of the crates it considers
+/// fresh would not be recorded. The root file of each target is overwritten with its own content,
+/// which only updates its modification time.
+pub fn touch_workspace() -> Result<(), String> {
+    let output = Command::new("cargo")
+        .args(&["metadata", "--no-deps", "--format-version", "1"])
+        .output()
+        .map_err(|err| format!("could not run cargo metadata: {}", err))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_owned());
+    }
+    let metadata: serde_json::Value =
+        serde_json::from_slice(&output.stdout).map_err(|err| format!("invalid cargo metadata: {}", err))?;
+
+    let packages = metadata["packages"].as_array().into_iter().flat_map(|packages| packages);
+    for package in packages {
+        for target in package["targets"].as_array().into_iter().flat_map(|targets| targets) {
+            if let Some(path) = target["src_path"].as_str() {
+                touch(Path::new(path)).map_err(|err| format!("could not touch `{}`: {}", path, err))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Update the modification time of a file. Writing the same bytes without truncating the file
+/// first leaves it intact even if interrupted.
+fn touch(path: &Path) -> io::Result<()> {
+    let mut content = Vec::new();
+    fs::File::open(path)?.read_to_end(&mut content)?;
+    fs::OpenOptions::new().write(true).open(path)?.write_all(&content)
+}
+
+#[allow(print_stdout)]
+pub fn report(count: usize, baseline: &Path) {
+    println!("wrote {} finding(s) to `{}`", count, baseline.display());
+}
+
+impl Drop for RecordDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
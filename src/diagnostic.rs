@@ -0,0 +1,88 @@
+//! The messages printed by `cargo --message-format=json`, as far as `cargo clippy` needs them.
+
+use serde_json;
+
+/// A line printed by `cargo --message-format=json`.
+#[derive(Deserialize)]
+pub struct CargoMessage {
+    pub reason: String,
+    /// The crate the message is about.
+    #[serde(default)]
+    pub target: Option<Target>,
+    #[serde(default)]
+    pub message: Option<Diagnostic>,
+}
+
+impl CargoMessage {
+    /// The compiler messages in the output of `cargo --message-format=json`.
+    pub fn compiler_messages(output: &str) -> Vec<CargoMessage> {
+        output
+            .lines()
+            .filter_map(|line| serde_json::from_str::<CargoMessage>(line).ok())
+            .filter(|msg| msg.reason == "compiler-message" && msg.message.is_some())
+            .collect()
+    }
+
+    /// The compiler diagnostics in the output of `cargo --message-format=json`.
+    pub fn diagnostics(output: &str) -> Vec<Diagnostic> {
+        Self::compiler_messages(output)
+            .into_iter()
+            .filter_map(|msg| msg.message)
+            .collect()
+    }
+}
+
+/// A crate compiled by `cargo`.
+#[derive(Deserialize)]
+pub struct Target {
+    /// The absolute path of the root file of the crate.
+    pub src_path: String,
+}
+
+/// A diagnostic as serialized by `rustc --error-format=json`.
+#[derive(Deserialize)]
+pub struct Diagnostic {
+    pub message: String,
+    /// `error`, `warning`, `note` or `help`.
+    pub level: String,
+    #[serde(default)]
+    pub code: Option<DiagnosticCode>,
+    #[serde(default)]
+    pub spans: Vec<DiagnosticSpan>,
+    #[serde(default)]
+    pub children: Vec<Diagnostic>,
+}
+
+impl Diagnostic {
+    /// The span the diagnostic is about.
+    pub fn primary_span(&self) -> Option<&DiagnosticSpan> {
+        self.spans.iter().find(|span| span.is_primary)
+    }
+}
+
+/// The lint name for lints, the error code for errors.
+#[derive(Deserialize)]
+pub struct DiagnosticCode {
+    pub code: String,
+}
+
+#[derive(Deserialize)]
+pub struct DiagnosticSpan {
+    /// Relative to the workspace root.
+    pub file_name: String,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    /// 1-based.
+    pub line_start: usize,
+    pub line_end: usize,
+    /// 1-based, in characters.
+    pub column_start: usize,
+    pub column_end: usize,
+    pub is_primary: bool,
+    #[serde(default)]
+    pub suggested_replacement: Option<String>,
+    #[serde(default)]
+    pub suggestion_approximate: Option<bool>,
+    #[serde(default)]
+    pub expansion: Option<serde_json::Value>,
+}
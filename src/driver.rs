@@ -10,8 +10,14 @@ extern crate rustc_driver;
 extern crate rustc_errors;
 extern crate rustc_plugin;
 extern crate rustc_trans_utils;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
 extern crate syntax;
 
+mod lint_list;
+
 use rustc_driver::{driver, Compilation, CompilerCalls, RustcDefaultCalls};
 use rustc_trans_utils::trans_crate::TransCrate;
 use rustc::session::{config, Session};
@@ -90,7 +96,8 @@ impl<'a> CompilerCalls<'a> for ClippyCompilerCalls {
                             .span,
                     );
                     registry.args_hidden = Some(Vec::new());
-                    clippy_lints::register_plugins(&mut registry);
+                    let conf = clippy_lints::read_conf(&registry);
+                    clippy_lints::register_plugins(&mut registry, &conf);
 
                     let rustc_plugin::registry::Registry {
                         early_lint_passes,
@@ -115,6 +122,11 @@ impl<'a> CompilerCalls<'a> for ClippyCompilerCalls {
 
                     sess.plugin_llvm_passes.borrow_mut().extend(llvm_passes);
                     sess.plugin_attributes.borrow_mut().extend(attributes);
+                    // the lint groups must be registered to apply the levels of the configuration
+                    drop(ls);
+
+                    let krate = state.krate.as_mut().expect("the crate is parsed at this point");
+                    clippy_lints::utils::levels::apply(krate, sess, &conf);
                 }
                 old(state);
             });
@@ -129,6 +141,22 @@ fn show_version() {
     println!(env!("CARGO_PKG_VERSION"));
 }
 
+/// Print the documentation of a lint for `--explain`. Returns false if the lint does not exist.
+#[allow(print_stdout)]
+fn explain(name: &str) -> bool {
+    use clippy_lints::utils::metadata;
+
+    if let Some(lint) = metadata::find(name) {
+        print!("{}", lint.explain());
+        return true;
+    }
+    match metadata::suggest(name) {
+        Some(lint) => eprintln!("error: no lint named `{}`, did you mean `{}`?", name, lint.name()),
+        None => eprintln!("error: no lint named `{}`", name),
+    }
+    false
+}
+
 pub fn main() {
     use std::env;
 
@@ -137,6 +165,37 @@ pub fn main() {
         return;
     }
 
+    // `--explain E0308` is handled by rustc
+    let args: Vec<String> = env::args().collect();
+    if let Some(pos) = args.iter().position(|a| a == "--explain") {
+        if let Some(name) = args.get(pos + 1) {
+            let is_error_code = name.len() > 1 && name.starts_with('E') && name[1..].chars().all(|c| c.is_digit(10));
+            if !is_error_code {
+                std::process::exit(if explain(name) { 0 } else { 1 });
+            }
+        }
+    }
+    if let Some(arg) = args.iter().find(|a| a.starts_with("--list-lints")) {
+        let format = match &arg["--list-lints".len()..] {
+            "" => Some(lint_list::Format::Text),
+            format if format.starts_with('=') => lint_list::Format::parse(&format[1..]),
+            _ => None,
+        };
+        match format {
+            Some(format) => {
+                rustc_driver::run(move || {
+                    lint_list::print(format);
+                    (Ok(()), None)
+                });
+                return;
+            },
+            None => {
+                eprintln!("error: unknown format in `{}`, expected `text` or `json`", arg);
+                std::process::exit(1);
+            },
+        }
+    }
+
     let sys_root = option_env!("SYSROOT")
         .map(String::from)
         .or_else(|| std::env::var("SYSROOT").ok())
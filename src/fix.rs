@@ -0,0 +1,278 @@
+//! Support for `cargo clippy --fix`: collect the suggestions emitted by the driver and apply them.
+
+use diagnostic::CargoMessage;
+use serde_json;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// A single replacement of a byte range in a source file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Replacement {
+    pub file: PathBuf,
+    pub start: usize,
+    pub end: usize,
+    pub snippet: String,
+}
+
+impl Replacement {
+    /// Whether both replacements touch the same bytes. Two insertions at the same position overlap
+    /// too: the order in which they would be applied is not known.
+    fn overlaps(&self, other: &Self) -> bool {
+        self.file == other.file
+            && (self.start < other.end && other.start < self.end
+                || (self.start, self.end) == (other.start, other.end))
+    }
+}
+
+/// All the replacements of one suggestion, they must be applied together or not at all.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Suggestion {
+    pub lint: String,
+    pub replacements: Vec<Replacement>,
+}
+
+impl Suggestion {
+    fn overlaps(&self, other: &Self) -> bool {
+        self.replacements
+            .iter()
+            .any(|rep| other.replacements.iter().any(|other| rep.overlaps(other)))
+    }
+}
+
+/// Run `cargo` with the given arguments, apply every suggestion of the `lints` that does not overlap
+/// with another one and check the crate again to verify the result.
+pub fn run(lints: &HashSet<String>, args: &[String], envs: &[(&str, std::ffi::OsString)]) -> Result<(), i32> {
+    let workspace_root = workspace_root()?;
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(args)
+        .arg("--message-format=json")
+        .stdout(Stdio::piped());
+    for &(key, ref value) in envs {
+        cmd.env(key, value);
+    }
+    let mut child = cmd.spawn().expect("could not run cargo");
+    let mut output = String::new();
+    child
+        .stdout
+        .take()
+        .expect("stdout is piped")
+        .read_to_string(&mut output)
+        .expect("could not read cargo output");
+    child.wait().expect("failed to wait for cargo?");
+
+    let suggestions = collect_suggestions(&output, &workspace_root, lints);
+    let (applied, skipped) = apply_suggestions(suggestions).map_err(|err| {
+        eprintln!("error: could not apply suggestions: {}", err);
+        1
+    })?;
+    report(&applied, skipped, &workspace_root);
+
+    // check again so the user sees what is left and we notice if a fix broke the build
+    let mut cmd = Command::new("cargo");
+    cmd.args(args);
+    for &(key, ref value) in envs {
+        cmd.env(key, value);
+    }
+    let exit_status = cmd.spawn()
+        .expect("could not run cargo")
+        .wait()
+        .expect("failed to wait for cargo?");
+
+    if exit_status.success() {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+/// Ask `cargo metadata` for the workspace root, the file names in the diagnostics are relative to it.
+fn workspace_root() -> Result<PathBuf, i32> {
+    let output = Command::new("cargo")
+        .args(&["metadata", "--no-deps", "--format-version", "1"])
+        .output()
+        .expect("could not run cargo metadata");
+    if !output.status.success() {
+        io::stderr().write_all(&output.stderr).ok();
+        return Err(output.status.code().unwrap_or(-1));
+    }
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout).expect("cargo metadata emits valid json");
+    Ok(metadata["workspace_root"]
+        .as_str()
+        .map(PathBuf::from)
+        .expect("cargo metadata has a workspace root"))
+}
+
+/// Extract the suggestions of the Clippy `lints` that can be applied without human review from the
+/// cargo output. The suggestions of `rustc`, like the similar names of unknown methods, are guesses
+/// even when they are not marked approximate.
+///
+/// Each child of a diagnostic carrying replacements is one suggestion. `rustc` flattens the
+/// alternatives of a suggestion into the spans of a single child, and a lint can also offer
+/// alternatives as several children: the diagnostics whose replacements overlap offer a choice
+/// a human has to make, and are skipped entirely.
+pub fn collect_suggestions(output: &str, workspace_root: &Path, lints: &HashSet<String>) -> Vec<Suggestion> {
+    let mut seen = HashSet::new();
+    let mut suggestions = Vec::new();
+
+    for diag in CargoMessage::diagnostics(output) {
+        let lint = match diag.code {
+            Some(ref code) if lints.contains(&code.code) => code.code.clone(),
+            _ => continue,
+        };
+
+        let mut candidates: Vec<(Suggestion, bool)> = Vec::new();
+        for child in &diag.children {
+            let spans = child.spans.iter().filter(|span| span.suggested_replacement.is_some());
+            let mut replacements: Vec<Replacement> = Vec::new();
+            let mut applicable = true;
+            for span in spans {
+                // approximate suggestions and suggestions in macros need a human
+                applicable &= span.suggestion_approximate != Some(true) && span.expansion.is_none()
+                    && !span.file_name.starts_with('<');
+                replacements.push(Replacement {
+                    file: workspace_root.join(&span.file_name),
+                    start: span.byte_start,
+                    end: span.byte_end,
+                    snippet: span.suggested_replacement.clone().expect("filtered above"),
+                });
+            }
+            if replacements.is_empty() {
+                continue;
+            }
+            replacements.sort();
+
+            let suggestion = Suggestion {
+                lint: lint.clone(),
+                replacements,
+            };
+            candidates.push((suggestion, applicable));
+        }
+
+        let alternatives = candidates.iter().enumerate().any(|(i, &(ref suggestion, _))| {
+            let reps = &suggestion.replacements;
+            reps.iter()
+                .enumerate()
+                .any(|(j, rep)| reps[j + 1..].iter().any(|other| rep.overlaps(other)))
+                || candidates[i + 1..]
+                    .iter()
+                    .any(|&(ref other, _)| suggestion.overlaps(other))
+        });
+        if alternatives {
+            continue;
+        }
+
+        for (suggestion, applicable) in candidates {
+            // the same crate can be checked several times (eg. as a lib and as a test)
+            if applicable && seen.insert(suggestion.clone()) {
+                suggestions.push(suggestion);
+            }
+        }
+    }
+
+    suggestions
+}
+
+/// Select the suggestions to apply, in order.
+///
+/// Suggestions overlapping with an already accepted suggestion are skipped, they will be reported
+/// again when `cargo clippy --fix` is run another time. Returns the accepted suggestions and the
+/// number of skipped suggestions.
+pub fn select_suggestions(suggestions: Vec<Suggestion>) -> (Vec<Suggestion>, usize) {
+    let mut accepted: Vec<Suggestion> = Vec::new();
+    let mut skipped = 0;
+
+    for suggestion in suggestions {
+        if accepted.iter().any(|old| suggestion.overlaps(old)) {
+            skipped += 1;
+        } else {
+            accepted.push(suggestion);
+        }
+    }
+
+    (accepted, skipped)
+}
+
+/// Apply non-overlapping replacements to the content of a file.
+pub fn apply_replacements(source: &str, mut reps: Vec<&Replacement>) -> Option<String> {
+    let mut source = source.to_owned();
+
+    // apply from the end of the file so earlier byte offsets stay valid
+    reps.sort_by(|a, b| b.start.cmp(&a.start));
+    for rep in reps {
+        if rep.start > rep.end || rep.end > source.len() || !source.is_char_boundary(rep.start)
+            || !source.is_char_boundary(rep.end)
+        {
+            return None;
+        }
+        source.replace_range(rep.start..rep.end, &rep.snippet);
+    }
+
+    Some(source)
+}
+
+/// Apply the suggestions to the source files.
+///
+/// Returns the applied suggestions per file and the number of skipped suggestions.
+fn apply_suggestions(suggestions: Vec<Suggestion>) -> io::Result<(BTreeMap<PathBuf, Vec<String>>, usize)> {
+    let (accepted, skipped) = select_suggestions(suggestions);
+
+    let mut per_file: BTreeMap<&Path, Vec<&Replacement>> = BTreeMap::new();
+    let mut applied: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+    for suggestion in &accepted {
+        for rep in &suggestion.replacements {
+            per_file.entry(&rep.file).or_insert_with(Vec::new).push(rep);
+        }
+        let file = suggestion.replacements[0].file.clone();
+        applied.entry(file).or_insert_with(Vec::new).push(suggestion.lint.clone());
+    }
+
+    for (file, reps) in per_file {
+        let mut source = String::new();
+        fs::File::open(file)?.read_to_string(&mut source)?;
+
+        let source = apply_replacements(&source, reps).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("suggestion out of bounds in `{}`", file.display()),
+            )
+        })?;
+
+        fs::File::create(file)?.write_all(source.as_bytes())?;
+    }
+
+    Ok((applied, skipped))
+}
+
+#[allow(print_stdout)]
+fn report(applied: &BTreeMap<PathBuf, Vec<String>>, skipped: usize, workspace_root: &Path) {
+    if applied.is_empty() {
+        println!("no machine-applicable suggestions found");
+    }
+    for (file, lints) in applied {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for lint in lints {
+            *counts.entry(lint).or_insert(0) += 1;
+        }
+        let counts = counts
+            .iter()
+            .map(|(lint, count)| format!("{} ({})", lint, count))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "fixed {} in {}: {}",
+            lints.len(),
+            file.strip_prefix(workspace_root).unwrap_or(file).display(),
+            counts
+        );
+    }
+    if skipped > 0 {
+        println!(
+            "skipped {} overlapping suggestion(s), run `cargo clippy --fix` again to apply them",
+            skipped
+        );
+    }
+}
@@ -23,7 +23,16 @@ pub fn plugin_registrar(reg: &mut Registry) {
         }
     }
 
-    clippy_lints::register_plugins(reg);
+    let conf = clippy_lints::read_conf(reg);
+    let has_levels = !conf.lints.is_empty() || conf.overrides
+        .iter()
+        .any(|o| !o.allow.is_empty() || !o.warn.is_empty() || !o.deny.is_empty());
+    if has_levels {
+        reg.sess
+            .struct_warn("the lint levels of Clippy's configuration file are only applied by `cargo clippy`")
+            .emit();
+    }
+    clippy_lints::register_plugins(reg, &conf);
 }
 
 // only exists to let the dogfood integration test works.
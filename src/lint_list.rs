@@ -0,0 +1,116 @@
+//! Support for `cargo clippy --list-lints`: list the lints `register_plugins` registers, with
+//! their groups and default levels, and the deprecated lints.
+
+use clippy_lints;
+use clippy_lints::utils::conf::Conf;
+use clippy_lints::utils::docs_url;
+use clippy_lints::utils::metadata::DEPRECATED;
+use rustc::session::{build_session, config};
+use rustc_errors::registry::Registry as DiagnosticRegistry;
+use rustc_plugin::registry::Registry;
+use serde_json;
+use std::collections::{BTreeMap, BTreeSet};
+use syntax::codemap::DUMMY_SP;
+
+/// The output format of the list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    /// Parse the value of `--list-lints=FORMAT`.
+    pub fn parse(format: &str) -> Option<Self> {
+        match format {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct LintInfo {
+    name: String,
+    /// The groups the lint is in, eg. `clippy` and `clippy_style`.
+    groups: BTreeSet<&'static str>,
+    default_level: &'static str,
+    description: &'static str,
+    docs_url: String,
+}
+
+#[derive(Serialize)]
+struct DeprecatedInfo {
+    name: String,
+    reason: &'static str,
+}
+
+#[derive(Serialize)]
+struct LintList {
+    lints: Vec<LintInfo>,
+    deprecated: Vec<DeprecatedInfo>,
+}
+
+/// Build the list from the registry `register_plugins` fills, as for a real compilation. The
+/// configuration does not change which lints are registered, so the default one is used.
+fn collect() -> LintList {
+    let sess = build_session(config::basic_options(), None, DiagnosticRegistry::new(&[]));
+    let mut registry = Registry::new(&sess, DUMMY_SP);
+    clippy_lints::register_plugins(&mut registry, &Conf::default());
+
+    let mut lints = BTreeMap::new();
+    let early = registry.early_lint_passes.iter().flat_map(|pass| pass.get_lints());
+    let late = registry.late_lint_passes.iter().flat_map(|pass| pass.get_lints());
+    for lint in early.chain(late) {
+        lints.entry(lint.name_lower()).or_insert_with(|| LintInfo {
+            name: lint.name_lower(),
+            groups: BTreeSet::new(),
+            default_level: lint.default_level.as_str(),
+            description: lint.desc,
+            docs_url: docs_url(&lint.name_lower()),
+        });
+    }
+    for (&group, ids) in &registry.lint_groups {
+        for id in ids {
+            if let Some(lint) = lints.get_mut(&id.to_string()) {
+                lint.groups.insert(group);
+            }
+        }
+    }
+
+    LintList {
+        lints: lints.into_iter().map(|(_, lint)| lint).collect(),
+        deprecated: DEPRECATED
+            .iter()
+            .map(|lint| DeprecatedInfo {
+                name: lint.name(),
+                reason: lint.reason,
+            })
+            .collect(),
+    }
+}
+
+#[allow(print_stdout)]
+pub fn print(format: Format) {
+    let list = collect();
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(&list).expect("the list is serializable")),
+        Format::Text => {
+            println!("{:<40} {:<8} {:<40} {}", "name", "default", "groups", "description");
+            for lint in &list.lints {
+                let groups = lint.groups.iter().cloned().collect::<Vec<_>>().join(", ");
+                println!(
+                    "{:<40} {:<8} {:<40} {}",
+                    lint.name, lint.default_level, groups, lint.description
+                );
+            }
+            if !list.deprecated.is_empty() {
+                println!("\ndeprecated lints:");
+                for lint in &list.deprecated {
+                    println!("{:<40} {}", lint.name, lint.reason);
+                }
+            }
+        },
+    }
+}
@@ -3,6 +3,18 @@
 #![feature(rustc_private)]
 #![allow(unknown_lints, missing_docs_in_private_items)]
 
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
+
+mod baseline;
+mod diagnostic;
+mod fix;
+mod message_format;
+mod metrics;
+
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
 Usage:
@@ -11,6 +23,18 @@ Usage:
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+    --fix                    Apply the machine-applicable suggestions to the
+                             source files and check the package again
+    --baseline <FILE>        Do not report the findings recorded in FILE
+    --write-baseline         Record the current findings in the baseline file
+                             (`clippy.baseline` unless `--baseline` is given)
+    --metrics <FILE>         Write the complexity and size of every function and
+                             item to FILE, as JSON or CSV from its extension
+    --message-format <FMT>   Also accepts `sarif` and `checkstyle`, printed on
+                             stdout once the package is checked
+    --explain <LINT>         Print the documentation of a lint and exit
+    --list-lints[=FORMAT]    List the lints with their groups and default levels
+                             and exit, FORMAT is `text` (default) or `json`
 
 Other options are the same as `cargo check`.
 
@@ -54,29 +78,116 @@ pub fn main() {
     }
 }
 
+/// The path of `clippy-driver`, installed next to `cargo-clippy`.
+fn driver_path() -> std::path::PathBuf {
+    let mut path = std::env::current_exe()
+        .expect("current executable path invalid")
+        .with_file_name("clippy-driver");
+    if cfg!(windows) {
+        path.set_extension("exe");
+    }
+    path
+}
+
+/// Run the driver on its own, for the options answered from the lint metadata compiled into it,
+/// like `--explain`.
+fn run_driver(args: &[String]) -> Result<(), i32> {
+    let status = std::process::Command::new(driver_path())
+        .args(args)
+        .status()
+        .expect("could not run clippy-driver");
+    if status.success() {
+        Ok(())
+    } else {
+        Err(status.code().unwrap_or(-1))
+    }
+}
+
 fn process<I>(mut old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
 {
     let mut args = vec!["check".to_owned()];
 
-    let mut found_dashes = false;
-    for arg in old_args.by_ref() {
-        found_dashes |= arg == "--";
-        if found_dashes {
+    let mut fix = false;
+    let mut message_format = None;
+    let mut baseline_file = None;
+    let mut write_baseline = false;
+    let mut metrics_file = None;
+    while let Some(arg) = old_args.next() {
+        if arg == "--" {
             break;
         }
-        args.push(arg);
+        if arg == "--fix" {
+            fix = true;
+        } else if arg == "--write-baseline" {
+            write_baseline = true;
+        } else if arg == "--baseline" {
+            match old_args.next() {
+                Some(file) => baseline_file = Some(file),
+                None => {
+                    eprintln!("error: `--baseline` needs a file name");
+                    return Err(1);
+                },
+            }
+        } else if arg == "--metrics" {
+            match old_args.next() {
+                Some(file) => metrics_file = Some(file),
+                None => {
+                    eprintln!("error: `--metrics` needs a file name");
+                    return Err(1);
+                },
+            }
+        } else if arg == "--explain" {
+            return match old_args.next() {
+                Some(lint) => run_driver(&[arg, lint]),
+                None => {
+                    eprintln!("error: `--explain` needs a lint name");
+                    Err(1)
+                },
+            };
+        } else if arg == "--message-format" || arg.starts_with("--message-format=") {
+            let format = if arg == "--message-format" {
+                match old_args.next() {
+                    Some(format) => format,
+                    None => {
+                        eprintln!("error: `--message-format` needs a format");
+                        return Err(1);
+                    },
+                }
+            } else {
+                arg["--message-format=".len()..].to_owned()
+            };
+            match message_format::Format::parse(&format) {
+                Some(format) => message_format = Some(format),
+                None => args.push(format!("--message-format={}", format)),
+            }
+        } else if arg.starts_with("--list-lints") {
+            return run_driver(&[arg]);
+        } else if arg.starts_with("--baseline=") {
+            baseline_file = Some(arg["--baseline=".len()..].to_owned());
+        } else if arg.starts_with("--metrics=") {
+            metrics_file = Some(arg["--metrics=".len()..].to_owned());
+        } else {
+            args.push(arg);
+        }
+    }
+    if fix && write_baseline {
+        eprintln!("error: `--fix` and `--write-baseline` cannot be used together");
+        return Err(1);
+    }
+    if fix && metrics_file.is_some() {
+        eprintln!("error: `--fix` and `--metrics` cannot be used together");
+        return Err(1);
+    }
+    if fix && message_format.is_some() {
+        eprintln!("error: `--fix` cannot be used with `--message-format sarif` or `checkstyle`");
+        return Err(1);
     }
 
     let clippy_args: String = old_args.map(|arg| format!("{}__CLIPPY_HACKERY__", arg)).collect();
 
-    let mut path = std::env::current_exe()
-        .expect("current executable path invalid")
-        .with_file_name("clippy-driver");
-    if cfg!(windows) {
-        path.set_extension("exe");
-    }
+    let path = driver_path();
 
     let target_dir = std::env::var_os("CLIPPY_DOGFOOD")
         .map(|_| {
@@ -96,19 +207,123 @@ where
         })
         .map(|p| ("CARGO_TARGET_DIR", p));
 
-    let exit_status = std::process::Command::new("cargo")
-        .args(&args)
-        .env("RUSTC_WRAPPER", path)
-        .env("CLIPPY_ARGS", clippy_args)
-        .envs(target_dir)
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
-
-    if exit_status.success() {
-        Ok(())
+    let mut envs = vec![
+        ("RUSTC_WRAPPER", path.clone().into_os_string()),
+        ("CLIPPY_ARGS", clippy_args.into()),
+    ];
+    envs.extend(target_dir);
+
+    // the driver runs in the workspace root, so it needs an absolute path
+    let baseline_given = baseline_file.is_some();
+    let baseline_file = std::env::current_dir()
+        .expect("current directory invalid")
+        .join(baseline_file.unwrap_or_else(|| baseline::DEFAULT_FILE.to_owned()));
+    let record_dir = if write_baseline {
+        // a crate `cargo` considers fresh is not checked, its findings would be missing
+        if let Err(err) = baseline::touch_workspace() {
+            eprintln!("error: could not make cargo check the workspace again: {}", err);
+            return Err(1);
+        }
+        let dir = baseline::RecordDir::new().map_err(|err| {
+            eprintln!("error: could not create a temporary directory: {}", err);
+            1
+        })?;
+        envs.push(("CLIPPY_WRITE_BASELINE", dir.path().as_os_str().to_owned()));
+        Some(dir)
     } else {
-        Err(exit_status.code().unwrap_or(-1))
+        if baseline_given {
+            if let Err(err) = std::fs::File::open(&baseline_file) {
+                eprintln!("error: could not read `{}`: {}", baseline_file.display(), err);
+                return Err(1);
+            }
+            envs.push(("CLIPPY_BASELINE", baseline_file.clone().into_os_string()));
+        }
+        None
+    };
+
+    let metrics_report = match metrics_file {
+        Some(file) => {
+            let file = std::env::current_dir().expect("current directory invalid").join(file);
+            let format = match metrics::Format::from_path(&file) {
+                Some(format) => format,
+                None => {
+                    eprintln!("error: `--metrics` writes `.json` or `.csv` files, not `{}`", file.display());
+                    return Err(1);
+                },
+            };
+            // a crate `cargo` considers fresh is not checked, its items would be missing
+            if !write_baseline {
+                if let Err(err) = baseline::touch_workspace() {
+                    eprintln!("error: could not make cargo check the workspace again: {}", err);
+                    return Err(1);
+                }
+            }
+            let dir = metrics::RecordDir::new().map_err(|err| {
+                eprintln!("error: could not create a temporary directory: {}", err);
+                1
+            })?;
+            envs.push(("CLIPPY_METRICS", dir.path().as_os_str().to_owned()));
+            Some((dir, file, format))
+        },
+        None => None,
+    };
+
+    if fix {
+        // a crate `cargo` considers fresh is not checked, its suggestions would be missing
+        if let Err(err) = baseline::touch_workspace() {
+            eprintln!("error: could not make cargo check the workspace again: {}", err);
+            return Err(1);
+        }
+        // only the suggestions of Clippy's lints are applied
+        let lints = message_format::lint_list(&path)?.into_iter().map(|(name, _)| name).collect();
+        return fix::run(&lints, &args, &envs);
     }
+
+    let exit_status = match message_format {
+        Some(format) => message_format::run(format, &path, &args, &envs),
+        None => {
+            let status = std::process::Command::new("cargo")
+                .args(&args)
+                .envs(envs)
+                .spawn()
+                .expect("could not run cargo")
+                .wait()
+                .expect("failed to wait for cargo?");
+            if status.success() {
+                Ok(())
+            } else {
+                Err(status.code().unwrap_or(-1))
+            }
+        },
+    };
+
+    if let Some(dir) = record_dir {
+        match dir.write_baseline(&baseline_file) {
+            Ok(Some(count)) => baseline::report(count, &baseline_file),
+            Ok(None) => {
+                eprintln!("error: no crate was checked, `{}` was not written", baseline_file.display());
+                return Err(1);
+            },
+            Err(err) => {
+                eprintln!("error: could not write `{}`: {}", baseline_file.display(), err);
+                return Err(1);
+            },
+        }
+    }
+
+    if let Some((dir, file, format)) = metrics_report {
+        match dir.write_report(&file, format) {
+            Ok(Some(count)) => metrics::report(count, &file),
+            Ok(None) => {
+                eprintln!("error: no crate was checked, `{}` was not written", file.display());
+                return Err(1);
+            },
+            Err(err) => {
+                eprintln!("error: could not write `{}`: {}", file.display(), err);
+                return Err(1);
+            },
+        }
+    }
+
+    exit_status
 }
@@ -0,0 +1,332 @@
+//! Support for `cargo clippy --message-format sarif|checkstyle`: convert the diagnostics of
+//! `cargo --message-format=json` for code scanning dashboards and CI servers.
+
+use diagnostic::{CargoMessage, Diagnostic, DiagnosticSpan};
+use serde_json::{self, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::env;
+use std::fmt::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// The formats `cargo` does not know about.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+    Sarif,
+    /// Checkstyle XML, as read by Jenkins and most CI servers.
+    Checkstyle,
+}
+
+impl Format {
+    /// Parse the value of `--message-format`, `None` for the formats handled by `cargo`.
+    pub fn parse(format: &str) -> Option<Self> {
+        match format {
+            "sarif" => Some(Format::Sarif),
+            "checkstyle" => Some(Format::Checkstyle),
+            _ => None,
+        }
+    }
+}
+
+/// A lint, as listed by `clippy-driver --list-lints=json`.
+#[derive(Deserialize)]
+pub struct Lint {
+    pub name: String,
+    pub groups: Vec<String>,
+    pub description: String,
+    pub docs_url: String,
+}
+
+#[derive(Deserialize)]
+struct LintList {
+    lints: Vec<Lint>,
+}
+
+/// Run `cargo` with the given arguments and print its diagnostics in `format`.
+pub fn run(format: Format, driver: &Path, args: &[String], envs: &[(&str, ::std::ffi::OsString)]) -> Result<(), i32> {
+    let lints = lint_list(driver)?;
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(args)
+        .arg("--message-format=json")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit());
+    for &(key, ref value) in envs {
+        cmd.env(key, value);
+    }
+    let output = cmd.output().expect("could not run cargo");
+    let status = output.status;
+    let output = String::from_utf8_lossy(&output.stdout).into_owned();
+
+    let reports = reports(&output, &env::current_dir().unwrap_or_default());
+    match format {
+        Format::Sarif => print(&sarif(&reports, &lints)),
+        Format::Checkstyle => print(&checkstyle(&reports, &lints)),
+    }
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(status.code().unwrap_or(-1))
+    }
+}
+
+/// Ask the driver for the groups and documentation links of the lints.
+pub fn lint_list(driver: &Path) -> Result<HashMap<String, Lint>, i32> {
+    let output = Command::new(driver)
+        .arg("--list-lints=json")
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| {
+            eprintln!("error: could not run `{}`: {}", driver.display(), err);
+            1
+        })?;
+    if !output.status.success() {
+        return Err(output.status.code().unwrap_or(-1));
+    }
+    parse_lint_list(&output.stdout).map_err(|err| {
+        eprintln!("error: could not read the lints listed by clippy-driver: {}", err);
+        1
+    })
+}
+
+/// Parse the output of `clippy-driver --list-lints=json`, by lint name.
+pub fn parse_lint_list(output: &[u8]) -> Result<HashMap<String, Lint>, serde_json::Error> {
+    let list: LintList = serde_json::from_slice(output)?;
+    Ok(list.lints.into_iter().map(|lint| (lint.name.clone(), lint)).collect())
+}
+
+/// A diagnostic to report.
+pub struct Report {
+    pub diagnostic: Diagnostic,
+    /// Where the diagnostic is reported: the file of its primary span or, for diagnostics without
+    /// one like the errors about the crate as a whole, the root file of the crate.
+    pub file: String,
+}
+
+impl Report {
+    /// The primary span of the diagnostic, `None` for diagnostics about the whole file.
+    fn span(&self) -> Option<&DiagnosticSpan> {
+        self.diagnostic.primary_span()
+    }
+}
+
+/// The diagnostics of the output of `cargo --message-format=json`. The paths of the root files
+/// of the crates are made relative to `root` like the paths of the spans.
+pub fn reports(output: &str, root: &Path) -> Vec<Report> {
+    // the same file can be checked several times (eg. as a lib and as a test)
+    let mut seen = HashSet::new();
+    CargoMessage::compiler_messages(output)
+        .into_iter()
+        .filter_map(|msg| {
+            let diag = msg.message?;
+            let file = match diag.primary_span() {
+                Some(span) => uri(&span.file_name),
+                None => {
+                    let src_path = msg.target?.src_path;
+                    let path = Path::new(&src_path);
+                    uri(&path.strip_prefix(root).unwrap_or(path).to_string_lossy())
+                },
+            };
+            Some(Report { diagnostic: diag, file })
+        })
+        .filter(|report| !is_summary(&report.diagnostic))
+        .filter(|report| {
+            let span = report.span().map(|span| (span.byte_start, span.byte_end));
+            let diag = &report.diagnostic;
+            seen.insert((rule_id(diag).map(str::to_owned), report.file.clone(), span, diag.message.clone()))
+        })
+        .collect()
+}
+
+/// The messages closing the output of a failed compilation, which tell nothing new.
+fn is_summary(diag: &Diagnostic) -> bool {
+    diag.spans.is_empty()
+        && (diag.message.starts_with("aborting due to") || diag.message.starts_with("For more information about"))
+}
+
+/// The lint name or error code of a diagnostic.
+fn rule_id(diag: &Diagnostic) -> Option<&str> {
+    diag.code.as_ref().map(|code| &*code.code)
+}
+
+fn uri(file_name: &str) -> String {
+    file_name.replace('\\', "/")
+}
+
+fn sarif_level(level: &str) -> &'static str {
+    match level {
+        "error" | "error: internal compiler error" => "error",
+        "warning" => "warning",
+        _ => "note",
+    }
+}
+
+fn sarif_region(span: &DiagnosticSpan) -> Value {
+    json!({
+        "startLine": span.line_start,
+        "startColumn": span.column_start,
+        "endLine": span.line_end,
+        "endColumn": span.column_end
+    })
+}
+
+/// The suggestions of a diagnostic as SARIF fixes.
+fn sarif_fixes(diag: &Diagnostic) -> Vec<Value> {
+    diag.children
+        .iter()
+        .filter_map(|child| {
+            let mut changes: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+            for span in &child.spans {
+                if let Some(ref replacement) = span.suggested_replacement {
+                    changes.entry(uri(&span.file_name)).or_insert_with(Vec::new).push(json!({
+                        "deletedRegion": sarif_region(span),
+                        "insertedContent": { "text": replacement }
+                    }));
+                }
+            }
+            if changes.is_empty() {
+                return None;
+            }
+            let changes = changes
+                .into_iter()
+                .map(|(uri, replacements)| {
+                    json!({
+                        "artifactLocation": { "uri": uri, "uriBaseId": "%SRCROOT%" },
+                        "replacements": replacements
+                    })
+                })
+                .collect::<Vec<_>>();
+            Some(json!({
+                "description": { "text": child.message },
+                "artifactChanges": changes
+            }))
+        })
+        .collect()
+}
+
+#[allow(print_stdout)]
+fn print(output: &str) {
+    println!("{}", output);
+}
+
+/// The reports as a SARIF log.
+pub fn sarif(reports: &[Report], lints: &HashMap<String, Lint>) -> String {
+    let mut rules = BTreeMap::new();
+    let results = reports
+        .iter()
+        .map(|report| {
+            let diag = &report.diagnostic;
+            let mut location = json!({
+                "artifactLocation": { "uri": report.file, "uriBaseId": "%SRCROOT%" }
+            });
+            if let Some(span) = report.span() {
+                location["region"] = sarif_region(span);
+            }
+            let mut result = json!({
+                "level": sarif_level(&diag.level),
+                "message": { "text": diag.message },
+                "locations": [{ "physicalLocation": location }]
+            });
+            if let Some(id) = rule_id(diag) {
+                result["ruleId"] = json!(id);
+                if let Some(lint) = lints.get(id) {
+                    rules.entry(id).or_insert_with(|| {
+                        json!({
+                            "id": lint.name,
+                            "shortDescription": { "text": lint.description },
+                            "helpUri": lint.docs_url,
+                            "properties": { "tags": lint.groups }
+                        })
+                    });
+                }
+            }
+            let fixes = sarif_fixes(diag);
+            if !fixes.is_empty() {
+                result["fixes"] = json!(fixes);
+            }
+            result
+        })
+        .collect::<Vec<_>>();
+
+    let sarif = json!({
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "clippy",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/rust-lang-nursery/rust-clippy",
+                    "rules": rules.into_iter().map(|(_, rule)| rule).collect::<Vec<_>>()
+                }
+            },
+            "results": results
+        }]
+    });
+    serde_json::to_string_pretty(&sarif).expect("SARIF is serializable")
+}
+
+fn checkstyle_severity(level: &str) -> &'static str {
+    match level {
+        "error" | "error: internal compiler error" => "error",
+        "warning" => "warning",
+        _ => "info",
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The reports as Checkstyle XML.
+pub fn checkstyle(reports: &[Report], lints: &HashMap<String, Lint>) -> String {
+    let mut files: BTreeMap<&str, Vec<&Report>> = BTreeMap::new();
+    for report in reports {
+        files.entry(&report.file).or_insert_with(Vec::new).push(report);
+    }
+
+    let mut xml = String::new();
+    let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(xml, r#"<checkstyle version="4.3">"#);
+    for (file, reports) in files {
+        let _ = writeln!(xml, r#"<file name="{}">"#, escape_xml(file));
+        for report in reports {
+            let diag = &report.diagnostic;
+            // `source` is usually the name of the check, prefixed by the tool
+            let source = match rule_id(diag) {
+                Some(id) if lints.contains_key(id) => format!("clippy.{}", id),
+                Some(id) => format!("rustc.{}", id),
+                None => "rustc".to_owned(),
+            };
+            // the line is optional, diagnostics about the whole file have none
+            let position = report
+                .span()
+                .map(|span| format!(r#"line="{}" column="{}" "#, span.line_start, span.column_start))
+                .unwrap_or_default();
+            let _ = writeln!(
+                xml,
+                r#"<error {}severity="{}" message="{}" source="{}"/>"#,
+                position,
+                checkstyle_severity(&diag.level),
+                escape_xml(&diag.message),
+                escape_xml(&source)
+            );
+        }
+        let _ = writeln!(xml, "</file>");
+    }
+    let _ = write!(xml, "</checkstyle>");
+    xml
+}
@@ -0,0 +1,156 @@
+//! Support for `cargo clippy --metrics`: merge the measures recorded by the driver processes into
+//! a JSON or CSV report.
+//!
+//! The format of the recorded measures is described in `clippy_lints/src/utils/metrics.rs`.
+
+use serde_json;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+/// The columns of the report, in order.
+const METRICS: [&str; 5] = [
+    "cyclomatic_complexity",
+    "cognitive_complexity",
+    "arguments",
+    "lines",
+    "type_complexity",
+];
+
+/// The format of the report, from the extension of its file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Some(Format::Json),
+            Some("csv") => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// An item of the report: its file, its line and its path.
+pub type Item = (String, u64, String);
+
+/// A directory the driver processes record their measures in, removed when dropped.
+pub struct RecordDir(PathBuf);
+
+impl RecordDir {
+    pub fn new() -> io::Result<Self> {
+        let dir = ::std::env::temp_dir().join(format!("clippy-metrics-{}", ::std::process::id()));
+        fs::create_dir_all(&dir)?;
+        Ok(RecordDir(dir))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Merge the recorded measures and write them to `report`. Returns the number of items, or
+    /// `None` without touching `report` if no driver process ran.
+    ///
+    /// An item measured several times, eg. once as a library and once as a test, or a type
+    /// complexity for each type written in the item, keeps the largest value.
+    pub fn write_report(&self, report: &Path, format: Format) -> io::Result<Option<usize>> {
+        let mut items: BTreeMap<Item, BTreeMap<String, u64>> = BTreeMap::new();
+        let mut recorded = false;
+        for entry in fs::read_dir(&self.0)? {
+            recorded = true;
+            for line in io::BufReader::new(fs::File::open(entry?.path())?).lines() {
+                let line = line?;
+                let (metric, item, value) = parse(&line).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, format!("invalid measure `{}`", line))
+                })?;
+                let current = items
+                    .entry(item)
+                    .or_insert_with(BTreeMap::new)
+                    .entry(metric.to_owned())
+                    .or_insert(0);
+                *current = (*current).max(value);
+            }
+        }
+        if !recorded {
+            return Ok(None);
+        }
+
+        let mut file = io::BufWriter::new(fs::File::create(report)?);
+        match format {
+            Format::Json => write_json(&mut file, &items)?,
+            Format::Csv => write_csv(&mut file, &items)?,
+        }
+        Ok(Some(items.len()))
+    }
+}
+
+impl Drop for RecordDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Parse a recorded measure: the metric, the item and the value.
+pub fn parse(line: &str) -> Option<(&str, Item, u64)> {
+    let mut parts = line.split('\t');
+    match (parts.next(), parts.next(), parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(metric), Some(file), Some(line), Some(item), Some(value), None) => {
+            let item = (file.to_owned(), line.parse().ok()?, item.to_owned());
+            Some((metric, item, value.parse().ok()?))
+        },
+        _ => None,
+    }
+}
+
+/// An array with an object per item, without the metrics the item has no value for.
+pub fn write_json<W: Write>(out: &mut W, items: &BTreeMap<Item, BTreeMap<String, u64>>) -> io::Result<()> {
+    let items = items
+        .iter()
+        .map(|(&(ref file, line, ref item), values)| {
+            let mut object = json!({
+                "file": file,
+                "line": line,
+                "item": item,
+            });
+            for &metric in &METRICS {
+                if let Some(&value) = values.get(metric) {
+                    object[metric] = json!(value);
+                }
+            }
+            object
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_writer_pretty(&mut *out, &items)?;
+    writeln!(out)
+}
+
+/// A header line, then a line per item with empty cells for the metrics the item has no value for.
+pub fn write_csv<W: Write>(out: &mut W, items: &BTreeMap<Item, BTreeMap<String, u64>>) -> io::Result<()> {
+    writeln!(out, "file,line,item,{}", METRICS.join(","))?;
+    for (&(ref file, line, ref item), values) in items {
+        let cells = METRICS
+            .iter()
+            .map(|&metric| values.get(metric).map_or_else(String::new, u64::to_string))
+            .collect::<Vec<_>>();
+        writeln!(out, "{},{},{},{}", csv_field(file), line, csv_field(item), cells.join(","))?;
+    }
+    Ok(())
+}
+
+/// Quote a field if needed, the paths of the items in generic impls contain commas.
+pub fn csv_field(field: &str) -> String {
+    if field.contains(|c: char| c == ',' || c == '"' || c == '\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[allow(print_stdout)]
+pub fn report(count: usize, report: &Path) {
+    println!("wrote the metrics of {} item(s) to `{}`", count, report.display());
+}
@@ -0,0 +1,5 @@
+// no section matches this file, the global threshold of 3 applies
+
+pub fn three(_a: u8, _b: u8, _c: u8) {}
+
+pub fn four(_a: u8, _b: u8, _c: u8, _d: u8) {}
@@ -0,0 +1,85 @@
+//! The findings recorded by the driver, merged by `cargo clippy --write-baseline` and suppressed
+//! by `cargo clippy --baseline`.
+#![allow(dead_code)]
+
+extern crate clippy_lints;
+extern crate serde_json;
+
+#[path = "../src/baseline.rs"]
+mod baseline;
+
+use baseline::{merge, RecordDir};
+use clippy_lints::utils::baseline::{Baseline, Finding};
+use std::fs;
+use std::io::{Read, Write};
+
+fn line(lint: &str, file: &str, code: &str) -> String {
+    Finding::new(lint, file, code).to_line()
+}
+
+#[test]
+fn record_merge_and_suppress() {
+    // the library and the tests of a crate check `src/lib.rs` twice
+    let lib = [
+        line("needless_return", "src/lib.rs", "return 1;"),
+        line("needless_return", "src/lib.rs", "return 1;"),
+        line("single_match", "src/lib.rs", "match x { Some(y) => f(y), _ => () }"),
+    ].join("\n");
+    let test = [
+        line("needless_return", "src/lib.rs", "return 1;"),
+        line("needless_return", "src/lib.rs", "return 1;"),
+        line("single_match", "src/lib.rs", "match x { Some(y) => f(y), _ => () }"),
+        line("unit_cmp", "tests/it.rs", "() == ()"),
+    ].join("\n");
+    let (content, count) = merge(vec![lib.as_str(), test.as_str()]);
+    assert_eq!(count, 4);
+    assert!(content.starts_with("# "));
+
+    let mut baseline = Baseline::parse(&content).unwrap();
+    // the code moved and was reformatted
+    let code = "match x {\n    Some(y) => f(y),\n    _ => ()\n}";
+    let single_match = Finding::new("single_match", "src/lib.rs", code);
+    assert!(baseline.suppress(&single_match));
+    assert!(!baseline.suppress(&single_match));
+
+    // listed twice, suppressed twice
+    let needless_return = Finding::new("needless_return", "src/lib.rs", "return 1;");
+    assert!(baseline.suppress(&needless_return));
+    assert!(baseline.suppress(&needless_return));
+    assert!(!baseline.suppress(&needless_return));
+
+    assert!(baseline.suppress(&Finding::new("unit_cmp", "tests/it.rs", "() == ()")));
+
+    // new findings are reported
+    assert!(!baseline.suppress(&Finding::new("unit_cmp", "tests/other.rs", "() == ()")));
+    assert!(!baseline.suppress(&Finding::new("needless_return", "src/lib.rs", "return 2;")));
+}
+
+#[test]
+fn invalid_baseline() {
+    assert!(Baseline::parse("# comment\n\nneedless_return\tsrc/lib.rs\n").is_err());
+    assert!(Baseline::parse("# comment\n\n").is_ok());
+    let finding = Finding::new("needless_return", "src/lib.rs", "return 1;");
+    assert_eq!(Finding::parse(&finding.to_line()), Some(finding));
+}
+
+#[test]
+fn nothing_recorded() {
+    let dir = RecordDir::new().unwrap();
+    let file = dir.path().with_extension("baseline");
+    assert_eq!(dir.write_baseline(&file).unwrap(), None);
+    assert!(!file.exists());
+
+    // a driver process which found nothing
+    fs::File::create(dir.path().join("1.txt")).unwrap();
+    fs::File::create(dir.path().join("2.txt"))
+        .unwrap()
+        .write_all(line("unit_cmp", "src/lib.rs", "() == ()").as_bytes())
+        .unwrap();
+    assert_eq!(dir.write_baseline(&file).unwrap(), Some(1));
+    let mut content = String::new();
+    fs::File::open(&file).unwrap().read_to_string(&mut content).unwrap();
+    fs::remove_file(&file).unwrap();
+    let mut baseline = Baseline::parse(&content).unwrap();
+    assert!(baseline.suppress(&Finding::new("unit_cmp", "src/lib.rs", "() == ()")));
+}
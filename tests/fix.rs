@@ -0,0 +1,150 @@
+//! The selection and the application of the suggestions by `cargo clippy --fix`.
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
+
+#[path = "../src/diagnostic.rs"]
+mod diagnostic;
+#[path = "../src/fix.rs"]
+mod fix;
+
+use fix::{apply_replacements, collect_suggestions, select_suggestions, Replacement, Suggestion};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+fn span(start: usize, end: usize, replacement: &str) -> Value {
+    json!({
+        "file_name": "src/lib.rs",
+        "byte_start": start,
+        "byte_end": end,
+        "line_start": 1,
+        "line_end": 1,
+        "column_start": start + 1,
+        "column_end": end + 1,
+        "is_primary": true,
+        "suggested_replacement": replacement,
+        "suggestion_approximate": false,
+        "expansion": null,
+    })
+}
+
+/// A line of cargo output with a diagnostic of `lint`, with a child per group of spans.
+fn message(lint: &str, children: Vec<Vec<Value>>) -> String {
+    let children = children
+        .into_iter()
+        .map(|spans| json!({ "message": "try", "level": "help", "spans": spans, "children": [] }))
+        .collect::<Vec<_>>();
+    json!({
+        "reason": "compiler-message",
+        "message": {
+            "message": "lint message",
+            "level": "warning",
+            "code": { "code": lint },
+            "spans": [span(0, 1, "")],
+            "children": children,
+        },
+    }).to_string()
+}
+
+fn replacement(start: usize, end: usize, snippet: &str) -> Replacement {
+    Replacement {
+        file: PathBuf::from("/ws/src/lib.rs"),
+        start,
+        end,
+        snippet: snippet.to_owned(),
+    }
+}
+
+fn collect(lines: &[String]) -> Vec<Suggestion> {
+    let lints = ["a", "b"].iter().map(|&lint| lint.to_owned()).collect::<HashSet<_>>();
+    collect_suggestions(&lines.join("\n"), Path::new("/ws"), &lints)
+}
+
+#[test]
+fn multipart_suggestion_is_kept_together() {
+    let suggestions = collect(&[message("a", vec![vec![span(0, 1, "x"), span(4, 5, "y")]])]);
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(
+        suggestions[0].replacements,
+        vec![replacement(0, 1, "x"), replacement(4, 5, "y")]
+    );
+}
+
+#[test]
+fn children_are_separate_suggestions() {
+    let suggestions = collect(&[message("a", vec![vec![span(0, 1, "x")], vec![span(4, 5, "y")]])]);
+    assert_eq!(suggestions.len(), 2);
+}
+
+#[test]
+fn alternatives_in_one_child_are_skipped() {
+    let suggestions = collect(&[message("a", vec![vec![span(0, 3, "x"), span(0, 3, "y")]])]);
+    assert!(suggestions.is_empty());
+}
+
+#[test]
+fn alternatives_in_several_children_are_skipped() {
+    let suggestions = collect(&[
+        message("a", vec![vec![span(0, 3, "x")], vec![span(1, 2, "y")]]),
+        message("b", vec![vec![span(5, 6, "z")]]),
+    ]);
+    assert_eq!(suggestions.len(), 1);
+    assert_eq!(suggestions[0].lint, "b");
+}
+
+#[test]
+fn approximate_suggestions_are_skipped() {
+    let mut approximate = span(0, 1, "x");
+    approximate["suggestion_approximate"] = json!(true);
+    let mut in_macro = span(2, 3, "y");
+    in_macro["expansion"] = json!({ "macro_decl_name": "m!" });
+    let suggestions = collect(&[message("a", vec![vec![approximate]]), message("b", vec![vec![in_macro]])]);
+    assert!(suggestions.is_empty());
+}
+
+#[test]
+fn rustc_suggestions_are_skipped() {
+    // eg. E0599 "no method named `lenght` found", with the help "did you mean `len`?"
+    let error = message("E0599", vec![vec![span(0, 6, "len")]]);
+    let lint = message("unused_mut", vec![vec![span(8, 12, "")]]);
+    assert!(collect(&[error, lint]).is_empty());
+}
+
+#[test]
+fn duplicates_are_collected_once() {
+    let line = message("a", vec![vec![span(0, 1, "x")]]);
+    assert_eq!(collect(&[line.clone(), line]).len(), 1);
+}
+
+#[test]
+fn overlapping_suggestions_are_skipped() {
+    let suggestion = |start, end| Suggestion {
+        lint: "a".to_owned(),
+        replacements: vec![replacement(start, end, "")],
+    };
+    let (accepted, skipped) = select_suggestions(vec![suggestion(0, 4), suggestion(2, 6), suggestion(4, 8)]);
+    assert_eq!(accepted, vec![suggestion(0, 4), suggestion(4, 8)]);
+    assert_eq!(skipped, 1);
+
+    // two insertions at the same place
+    let (accepted, skipped) = select_suggestions(vec![suggestion(3, 3), suggestion(3, 3)]);
+    assert_eq!(accepted.len(), 1);
+    assert_eq!(skipped, 1);
+}
+
+#[test]
+fn replacements_are_applied_from_the_end() {
+    let (a, b) = (replacement(0, 3, "let"), replacement(8, 9, "1 + 1"));
+    assert_eq!(apply_replacements("var x = 2;", vec![&a, &b]).unwrap(), "let x = 1 + 1;");
+    assert_eq!(apply_replacements("var x = 2;", vec![&b, &a]).unwrap(), "let x = 1 + 1;");
+}
+
+#[test]
+fn replacements_out_of_bounds_are_rejected() {
+    assert!(apply_replacements("abc", vec![&replacement(2, 5, "")]).is_none());
+    assert!(apply_replacements("é", vec![&replacement(1, 2, "")]).is_none());
+}
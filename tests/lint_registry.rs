@@ -0,0 +1,90 @@
+//! The lint registry must list every lint of the lint passes, and the lint list in the README and
+//! the CHANGELOG must match it.
+#![feature(rustc_private)]
+
+extern crate clippy_lints;
+extern crate rustc;
+extern crate rustc_errors;
+extern crate rustc_plugin;
+extern crate syntax;
+
+use clippy_lints::utils::metadata::{public_lints, DEPRECATED, LINTS};
+use rustc::session::{build_session, config};
+use rustc_errors::registry::Registry as DiagnosticRegistry;
+use rustc_plugin::registry::Registry;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::Read;
+use syntax::codemap::DUMMY_SP;
+
+const DOCS_LINK: &str = "https://rust-lang-nursery.github.io/rust-clippy/master/index.html";
+
+fn read(path: &str) -> String {
+    let mut content = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .expect("could not read file");
+    content
+}
+
+#[test]
+fn registry_lists_every_lint() {
+    syntax::with_globals(|| {
+        let sess = build_session(config::basic_options(), None, DiagnosticRegistry::new(&[]));
+        let mut registry = Registry::new(&sess, DUMMY_SP);
+        let conf = clippy_lints::read_conf(&registry);
+        clippy_lints::register_plugins(&mut registry, &conf);
+
+        let early = registry.early_lint_passes.iter().flat_map(|pass| pass.get_lints());
+        let late = registry.late_lint_passes.iter().flat_map(|pass| pass.get_lints());
+        let registered = early.chain(late).map(|lint| lint.name_lower()).collect::<BTreeSet<_>>();
+        let listed = LINTS.iter().map(|lint| lint.name()).collect::<BTreeSet<_>>();
+        assert!(
+            registered == listed,
+            "`utils::metadata::LINTS` does not match the lints of the lint passes, missing: {:?}, unknown: {:?}",
+            registered.difference(&listed).collect::<Vec<_>>(),
+            listed.difference(&registered).collect::<Vec<_>>()
+        );
+    });
+}
+
+#[test]
+fn readme_has_the_lint_count() {
+    let expected = format!(
+        "[There are {} lints included in this crate!]({})",
+        public_lints().count(),
+        DOCS_LINK
+    );
+    assert!(
+        read("README.md").lines().any(|line| line == expected),
+        "the lint count in README.md is wrong, it should be:\n{}",
+        expected
+    );
+}
+
+#[test]
+fn changelog_links_every_lint() {
+    let mut names = public_lints()
+        .map(|lint| lint.name())
+        .chain(DEPRECATED.iter().map(|lint| lint.name()))
+        .collect::<Vec<_>>();
+    names.sort();
+    let expected = names
+        .iter()
+        .map(|name| format!("[`{0}`]: {1}#{0}\n", name, DOCS_LINK))
+        .collect::<String>();
+
+    let changelog = read("CHANGELOG.md");
+    let start = "<!-- begin autogenerated links to wiki -->\n";
+    let end = "<!-- end autogenerated links to wiki -->";
+    let links = changelog
+        .find(start)
+        .map(|pos| &changelog[pos + start.len()..])
+        .and_then(|links| links.find(end).map(|pos| &links[..pos]))
+        .expect("the links are missing in CHANGELOG.md");
+    assert!(
+        links == expected,
+        "the lint links at the end of CHANGELOG.md are wrong, they should be:\n{}",
+        expected
+    );
+}
@@ -0,0 +1,36 @@
+//! `clippy-driver --list-lints`, which `cargo clippy` runs directly, not under `cargo`.
+
+extern crate serde_json;
+
+use serde_json::Value;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn clippy_driver_path() -> PathBuf {
+    if let Some(path) = option_env!("CLIPPY_DRIVER_PATH") {
+        PathBuf::from(path)
+    } else {
+        PathBuf::from(concat!("target/", env!("PROFILE"), "/clippy-driver"))
+    }
+}
+
+#[test]
+fn list_lints_outside_of_cargo() {
+    let output = Command::new(clippy_driver_path())
+        .arg("--list-lints=json")
+        .env_remove("CARGO_MANIFEST_DIR")
+        .env_remove("CLIPPY_CONF_FILE")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let list: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let lints = list["lints"].as_array().unwrap();
+    let map_entry = lints.iter().find(|lint| lint["name"] == "map_entry").unwrap();
+    assert_eq!(map_entry["default_level"], "warn");
+    assert!(map_entry["groups"].as_array().unwrap().contains(&Value::from("clippy_perf")));
+}
@@ -0,0 +1,167 @@
+//! The SARIF and Checkstyle reports of `cargo clippy --message-format`, compared with the files in
+//! `tests/message_format`.
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
+
+#[path = "../src/diagnostic.rs"]
+mod diagnostic;
+#[path = "../src/message_format.rs"]
+mod message_format;
+
+use message_format::{checkstyle, parse_lint_list, reports, sarif, Lint, Report};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn read(name: &str) -> String {
+    let mut content = String::new();
+    File::open(Path::new("tests/message_format").join(name))
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .expect("could not read file");
+    content
+}
+
+fn lint(name: &str, groups: &[&str], description: &str) -> (String, Lint) {
+    let lint = Lint {
+        name: name.to_owned(),
+        groups: groups.iter().map(|&group| group.to_owned()).collect(),
+        description: description.to_owned(),
+        docs_url: format!("https://rust-lang-nursery.github.io/rust-clippy/v0.0.193/index.html#{}", name),
+    };
+    (name.to_owned(), lint)
+}
+
+fn lints() -> HashMap<String, Lint> {
+    vec![
+        lint(
+            "ptr_arg",
+            &["clippy", "clippy_style"],
+            "fn arguments of the type `&Vec<...>` or `&String`, suggesting to use `&[...]` or `&str` instead, \
+             respectively",
+        ),
+        lint(
+            "single_char_pattern",
+            &["clippy", "clippy_perf"],
+            "using a single-character str where a char could be used, e.g. `_.split(\"x\")`",
+        ),
+    ].into_iter()
+        .collect()
+}
+
+/// The reports of `tests/message_format/cargo_output.json`, for a workspace in `/ws`.
+fn cargo_reports() -> Vec<Report> {
+    reports(&read("cargo_output.json"), Path::new("/ws"))
+}
+
+#[test]
+fn duplicates_and_summaries_are_skipped() {
+    let reports = cargo_reports();
+    let messages = reports
+        .iter()
+        .map(|report| (&*report.file, &*report.diagnostic.message))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            (
+                "src/lib.rs",
+                "writing `&Vec<_>` instead of `&[_]` involves one more reference and cannot be used with \
+                 non-Vec-based slices.",
+            ),
+            ("src/lib.rs", "single-character string constant used as pattern"),
+            ("src/main.rs", "mismatched types"),
+            // no span, reported on the root file of the crate
+            ("src/bin/tool.rs", "`main` function not found in crate `tool`"),
+        ]
+    );
+}
+
+#[test]
+fn sarif_output() {
+    assert_eq!(sarif(&cargo_reports(), &lints()), read("sarif.json").trim_right());
+}
+
+#[test]
+fn checkstyle_output() {
+    assert_eq!(checkstyle(&cargo_reports(), &lints()), read("checkstyle.xml").trim_right());
+}
+
+#[test]
+fn invalid_lint_list_is_an_error() {
+    assert!(parse_lint_list(b"thread 'main' panicked").is_err());
+    assert!(parse_lint_list(br#"{ "deprecated": [] }"#).is_err());
+    let lints = parse_lint_list(
+        br#"{
+            "lints": [{
+                "name": "ptr_arg",
+                "groups": ["clippy", "clippy_style"],
+                "default_level": "warn",
+                "description": "fn arguments of the type `&Vec<...>`",
+                "docs_url": "https://rust-lang-nursery.github.io/rust-clippy/v0.0.193/index.html#ptr_arg"
+            }],
+            "deprecated": []
+        }"#,
+    ).unwrap();
+    assert_eq!(lints["ptr_arg"].groups, vec!["clippy", "clippy_style"]);
+}
+
+fn cargo_clippy_path() -> PathBuf {
+    let path = PathBuf::from(concat!("target/", env!("PROFILE"), "/cargo-clippy"));
+    path.canonicalize().unwrap_or(path)
+}
+
+/// A package with a `ptr_arg` warning, in a temporary directory.
+fn write_package(dir: &Path) {
+    fs::create_dir_all(dir.join("src")).unwrap();
+    File::create(dir.join("Cargo.toml"))
+        .unwrap()
+        .write_all(b"[package]\nname = \"message_format_test\"\nversion = \"0.1.0\"\n\n[workspace]\n")
+        .unwrap();
+    File::create(dir.join("src/lib.rs"))
+        .unwrap()
+        .write_all(b"pub fn len(v: &Vec<u8>) -> usize {\n    v.len()\n}\n")
+        .unwrap();
+}
+
+#[test]
+fn sarif_of_a_package() {
+    if option_env!("RUSTC_TEST_SUITE").is_some() {
+        return;
+    }
+    let dir = std::env::temp_dir().join(format!("clippy-message-format-{}", std::process::id()));
+    write_package(&dir);
+    let output = Command::new(cargo_clippy_path())
+        .args(&["clippy", "--message-format", "sarif"])
+        .current_dir(&dir)
+        .env_remove("CARGO_MANIFEST_DIR")
+        .env("CARGO_TARGET_DIR", dir.join("target"))
+        .output()
+        .unwrap();
+    let _ = fs::remove_dir_all(&dir);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let log: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let run = &log["runs"][0];
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["ruleId"], "ptr_arg");
+    assert_eq!(
+        results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "src/lib.rs"
+    );
+    // the rule comes from `clippy-driver --list-lints=json`
+    let rule = &run["tool"]["driver"]["rules"][0];
+    assert_eq!(rule["id"], "ptr_arg");
+    assert!(rule["helpUri"].as_str().unwrap().ends_with("#ptr_arg"));
+}
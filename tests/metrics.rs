@@ -0,0 +1,107 @@
+//! The measures recorded by the driver, merged into a report by `cargo clippy --metrics`.
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate serde_json;
+
+#[path = "../src/metrics.rs"]
+mod metrics;
+
+use metrics::{csv_field, parse, write_csv, write_json, Format, Item, RecordDir};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Read, Write};
+
+const RECORDS: &str = "type_complexity\tsrc/lib.rs\t3\t<Foo<T, U>>::new\t120
+lines\tsrc/lib.rs\t10\tmain\t5
+cyclomatic_complexity\tsrc/lib.rs\t10\tmain\t2
+";
+
+fn items(records: &str) -> BTreeMap<Item, BTreeMap<String, u64>> {
+    let mut items = BTreeMap::new();
+    for line in records.lines() {
+        let (metric, item, value) = parse(line).unwrap();
+        items
+            .entry(item)
+            .or_insert_with(BTreeMap::new)
+            .insert(metric.to_owned(), value);
+    }
+    items
+}
+
+#[test]
+fn parse_measures() {
+    assert_eq!(
+        parse("arguments\tsrc/main.rs\t4\tcli::run\t3"),
+        Some(("arguments", ("src/main.rs".to_owned(), 4, "cli::run".to_owned()), 3))
+    );
+    assert_eq!(parse("arguments\tsrc/main.rs\t4\tcli::run"), None);
+    assert_eq!(parse("arguments\tsrc/main.rs\t4\tcli::run\t3\t1"), None);
+    assert_eq!(parse("arguments\tsrc/main.rs\tfour\tcli::run\t3"), None);
+    assert_eq!(parse("arguments\tsrc/main.rs\t4\tcli::run\t-3"), None);
+}
+
+#[test]
+fn quote_csv_fields() {
+    assert_eq!(csv_field("main"), "main");
+    assert_eq!(csv_field("<Foo<T, U>>::new"), "\"<Foo<T, U>>::new\"");
+    assert_eq!(csv_field("say\"hi\""), "\"say\"\"hi\"\"\"");
+}
+
+#[test]
+fn json_report() {
+    let mut out = Vec::new();
+    write_json(&mut out, &items(RECORDS)).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        r#"[
+  {
+    "file": "src/lib.rs",
+    "item": "<Foo<T, U>>::new",
+    "line": 3,
+    "type_complexity": 120
+  },
+  {
+    "cyclomatic_complexity": 2,
+    "file": "src/lib.rs",
+    "item": "main",
+    "line": 10,
+    "lines": 5
+  }
+]
+"#
+    );
+}
+
+#[test]
+fn csv_report() {
+    let mut out = Vec::new();
+    write_csv(&mut out, &items(RECORDS)).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "file,line,item,cyclomatic_complexity,cognitive_complexity,arguments,lines,type_complexity
+src/lib.rs,3,\"<Foo<T, U>>::new\",,,,,120
+src/lib.rs,10,main,2,,,5,
+"
+    );
+}
+
+#[test]
+fn report_is_only_written_if_a_crate_was_checked() {
+    let dir = RecordDir::new().unwrap();
+    let file = dir.path().with_extension("csv");
+    assert_eq!(dir.write_report(&file, Format::Csv).unwrap(), None);
+    assert!(!file.exists());
+
+    // a driver process which measured nothing, the largest value of an item is kept
+    fs::File::create(dir.path().join("1.txt")).unwrap();
+    fs::File::create(dir.path().join("2.txt"))
+        .unwrap()
+        .write_all(b"lines\tsrc/lib.rs\t10\tmain\t5\nlines\tsrc/lib.rs\t10\tmain\t7\n")
+        .unwrap();
+    assert_eq!(dir.write_report(&file, Format::Csv).unwrap(), Some(1));
+    let mut content = String::new();
+    fs::File::open(&file).unwrap().read_to_string(&mut content).unwrap();
+    fs::remove_file(&file).unwrap();
+    assert!(content.ends_with("\nsrc/lib.rs,10,main,,,,7,\n"));
+}
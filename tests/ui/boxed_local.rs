@@ -0,0 +1,44 @@
+#![feature(box_syntax)]
+#![warn(boxed_local)]
+#![allow(dead_code, unused_variables)]
+
+struct A;
+
+impl A {
+    fn foo(&self) {}
+}
+
+fn take_box(_: &Box<A>) {}
+
+fn warn_call() {
+    let x = box A;
+    x.foo();
+}
+
+fn warn_arg(x: Box<A>) {
+    x.foo();
+}
+
+fn warn_rename() {
+    let x = box A;
+    let y = x;
+    y.foo();
+}
+
+fn nowarn_return() -> Box<A> {
+    let x = box A;
+    x.foo();
+    x
+}
+
+fn nowarn_pass() {
+    let x = box A;
+    take_box(&x);
+}
+
+fn nowarn_move() {
+    let x = box A;
+    drop(x);
+}
+
+fn main() {}
@@ -0,0 +1,92 @@
+#![feature(custom_attribute)]
+
+#![warn(cognitive_complexity)]
+#![allow(unused)]
+
+fn main() {}
+
+#[cognitive_complexity = "0"]
+fn nested_ifs(x: u32) -> u32 {
+    if x > 1 {
+        if x > 2 {
+            if x > 3 {
+                return 3;
+            }
+        }
+    }
+    0
+}
+
+#[cognitive_complexity = "0"]
+fn dispatch(x: u32) -> &'static str {
+    match x {
+        0 => "zero",
+        1 => "one",
+        2 => "two",
+        3 => "three",
+        _ => "many",
+    }
+}
+
+#[cognitive_complexity = "0"]
+fn else_if_chain(x: u32) -> u32 {
+    if x == 0 {
+        1
+    } else if x == 1 {
+        2
+    } else {
+        3
+    }
+}
+
+#[cognitive_complexity = "0"]
+fn loops(v: &[Vec<u32>]) -> u32 {
+    let mut sum = 0;
+    'outer: for row in v {
+        for &x in row {
+            if x == 0 {
+                continue 'outer;
+            }
+            sum += x;
+        }
+    }
+    sum
+}
+
+#[cognitive_complexity = "0"]
+fn booleans(a: bool, b: bool, c: bool) -> bool {
+    if a && b && c {
+        return true;
+    }
+    a && b || c
+}
+
+#[cognitive_complexity = "0"]
+fn factorial(n: u64) -> u64 {
+    if n == 0 {
+        1
+    } else {
+        n * factorial(n - 1)
+    }
+}
+
+#[cognitive_complexity = "0"]
+fn desugared(v: &mut Vec<Option<u32>>) -> u32 {
+    let mut sum = 0;
+    while let Some(x) = v.pop() {
+        if let Some(x) = x {
+            sum += x;
+        } else {
+            let f = |y: u32| if y > 0 { y } else { 0 };
+            sum += f(1);
+        }
+    }
+    sum
+}
+
+#[cognitive_complexity = "0"]
+fn linear(x: u32) -> Result<u32, String> {
+    let y: u32 = "1".parse().map_err(|_| String::from("not a number"))?;
+    Ok(x + y)
+}
+
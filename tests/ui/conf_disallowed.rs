@@ -0,0 +1,24 @@
+// rustc-env:CLIPPY_CONF_FILE=tests/ui/conf_disallowed.toml
+
+#![warn(disallowed_methods, disallowed_types)]
+#![allow(dead_code, unused_variables)]
+
+use std::collections::HashMap;
+use std::env::set_var;
+
+struct Cache {
+    entries: HashMap<String, u32>,
+}
+
+fn main() {
+    std::env::set_var("RUST_LOG", "debug");
+    set_var("RUST_LOG", "debug");
+    let set = std::env::set_var;
+    let now = std::time::Instant::now();
+
+    let count = vec![1, 2, 3].iter().count();
+    let len = vec![1, 2, 3].len();
+
+    let map: HashMap<u32, u32> = HashMap::new();
+    let map = std::collections::BTreeMap::<u32, u32>::new();
+}
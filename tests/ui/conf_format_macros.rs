@@ -0,0 +1,39 @@
+// rustc-env:CLIPPY_CONF_FILE=tests/ui/conf_format_macros.toml
+
+#![warn(print_literal, useless_format)]
+#![allow(dead_code)]
+
+// configured as `log::info`, this one is not from `log`
+macro_rules! info {
+    ($($arg:tt)+) => {
+        ::std::fmt::format(format_args!($($arg)+))
+    };
+}
+
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        ::std::fmt::format(format_args!($($arg)+))
+    };
+}
+
+macro_rules! ui_msg {
+    ($($arg:tt)+) => {
+        format!($($arg)+)
+    };
+}
+
+macro_rules! not_configured {
+    ($($arg:tt)+) => {
+        format!($($arg)+)
+    };
+}
+
+fn main() {
+    let name = "Ferris";
+    info!("Hello {}", "world");
+    debug!("Hello {}", "world");
+    debug!("Hello {}", name);
+    let _ = ui_msg!("Hello");
+    let _ = ui_msg!("Hello {}", name);
+    let _ = not_configured!("Hello");
+}
@@ -0,0 +1,19 @@
+// rustc-env:CLIPPY_CONF_FILE=tests/ui/conf_lints.toml
+// compile-flags: -W single_match
+
+// `needless_return` is allowed by the configuration file, `single_match` is allowed by it too but
+// the command line wins
+
+fn one() -> u8 {
+    return 1;
+}
+
+fn dummy(_: u8) {}
+
+fn main() {
+    let x = Some(one());
+    match x {
+        Some(v) => dummy(v),
+        _ => (),
+    }
+}
@@ -0,0 +1,5 @@
+// rustc-env:CLIPPY_CONF_FILE=tests/ui/conf_misspelled_key.toml
+
+#![allow(too_many_arguments)]
+
+fn main() {}
@@ -0,0 +1,16 @@
+// rustc-env:CLIPPY_CONF_FILE=tests/ui/conf_overrides.toml
+
+#![allow(dead_code)]
+
+#[path = "../auxiliary/conf_overrides_module.rs"]
+mod module;
+
+// the matching section lowers the threshold to 1
+fn two(_a: u8, _b: u8) {}
+
+// and allows `option_unwrap_used`, which the `[lints]` table warns about
+fn unwrap(o: Option<u8>) -> u8 {
+    o.unwrap()
+}
+
+fn main() {}
@@ -0,0 +1,3 @@
+// rustc-env:CLIPPY_CONF_FILE=tests/ui/conf_overrides_invalid.toml
+
+fn main() {}
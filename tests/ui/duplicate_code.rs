@@ -0,0 +1,99 @@
+#![warn(duplicate_code)]
+#![allow(unused)]
+
+fn main() {}
+
+mod stats {
+    // not a copy of `mean`, the operator differs
+    pub fn product(values: &[f64]) -> f64 {
+        let mut product = 1.0;
+        let mut count = 0_u32;
+        for &v in values {
+            product *= v;
+            count += 1;
+        }
+        let count = count.max(1);
+        product / f64::from(count)
+    }
+
+    pub fn mean(values: &[f64]) -> f64 {
+        let mut sum = 0.0;
+        let mut count = 0_u32;
+        for &v in values {
+            sum += v;
+            count += 1;
+        }
+        let count = count.max(1);
+        sum / f64::from(count)
+    }
+}
+
+mod report {
+    // a copy of `stats::mean` with other names and literals
+    pub fn average(xs: &[f64]) -> f64 {
+        let mut total = 0.5;
+        let mut n = 0_u32;
+        for &x in xs {
+            total += x;
+            n += 1;
+        }
+        let n = n.max(2);
+        total / f64::from(n)
+    }
+}
+
+fn fill(v: &mut Vec<u32>, flag: bool) {
+    if flag {
+        v.push(1);
+        v.push(2);
+        v.sort();
+        v.dedup();
+        v.reverse();
+        v.truncate(4);
+    }
+}
+
+fn refill(w: &mut Vec<u32>) {
+    while w.len() < 10 {
+        w.push(3);
+        w.push(4);
+        w.sort();
+        w.dedup();
+        w.reverse();
+        w.truncate(8);
+    }
+}
+
+fn shrink(w: &mut Vec<u32>) {
+    if w.len() > 10 {
+        w.push(3);
+        w.push(4);
+        w.sort();
+        w.dedup();
+        w.reverse();
+        w.truncate(8);
+    }
+}
+
+// the same hash as `push_none`, the patterns are not hashed, but not a copy
+fn push_some(v: &mut Vec<u32>, o: Option<u32>) {
+    match o {
+        Some(_) => v.push(1),
+        _ => v.push(2),
+    }
+    v.sort();
+    v.dedup();
+    v.reverse();
+    v.truncate(4);
+}
+
+fn push_none(v: &mut Vec<u32>, o: Option<u32>) {
+    match o {
+        None => v.push(1),
+        _ => v.push(2),
+    }
+    v.sort();
+    v.dedup();
+    v.reverse();
+    v.truncate(4);
+}
@@ -1,10 +1,11 @@
 
 
+#![feature(nll)]
 #![allow(unused, needless_pass_by_value)]
 
 #![warn(map_entry)]
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::hash::Hash;
 
 fn foo() {}
@@ -41,5 +42,37 @@ fn insert_other_if_absent<K: Eq + Hash, V>(m: &mut HashMap<K, V>, k: K, o: K, v:
     if !m.contains_key(&k) { m.insert(o, v); }
 }
 
+fn insert_in_other_map<K: Eq + Hash, V>(m: &mut HashMap<K, V>, n: &mut HashMap<K, V>, k: K, v: V) {
+    if !m.contains_key(&k) { n.insert(k, v); }
+}
+
+fn insert_if_get_mut_fails<K: Eq + Hash, V>(m: &mut HashMap<K, V>, k: K, v: V) {
+    if let Some(x) = m.get_mut(&k) { foo(); } else { m.insert(k, v); }
+}
+
+fn insert_if_get_fails<K: Eq + Hash, V>(m: &mut HashMap<K, V>, k: K, v: V) -> Option<V> {
+    match m.get(&k) { None => m.insert(k, v), Some(_) => None }
+}
+
+fn remove_if_present<K: Eq + Hash, V>(m: &mut HashMap<K, V>, k: K) {
+    if m.contains_key(&k) { m.remove(&k); foo(); }
+}
+
+fn insert_in_set<K: Eq + Hash>(s: &mut HashSet<K>, k: K) {
+    if !s.contains(&k) { s.insert(k); }
+}
+
+fn insert_in_btreeset<K: Ord>(s: &mut BTreeSet<K>, k: K) {
+    if s.contains(&k) { foo(); } else { s.insert(k); foo(); }
+}
+
+fn remove_from_set<K: Eq + Hash>(s: &mut HashSet<K>, k: K) {
+    if s.contains(&k) { s.remove(&k); }
+}
+
+fn insert_computed_if_absent<K: Eq + Hash, V: Default>(m: &mut HashMap<K, V>, k: K) {
+    if !m.contains_key(&k) { m.insert(k, V::default()); }
+}
+
 fn main() {
 }
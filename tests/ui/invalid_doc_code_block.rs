@@ -0,0 +1,73 @@
+#![warn(invalid_doc_code_block)]
+#![allow(dead_code)]
+
+/// Adds one.
+///
+/// ```
+/// let x = add_one(1);
+/// assert_eq!(x, 2);
+/// ```
+fn add_one(x: u32) -> u32 {
+    x + 1
+}
+
+/// A missing expression.
+///
+/// ```
+/// let x = ;
+/// ```
+fn missing_expression() {}
+
+/// A missing operand, with a `main` function.
+///
+/// ```rust
+/// fn main() {
+///     let x = 1;
+///     let y = x +;
+/// }
+/// ```
+fn missing_operand() {}
+
+/// Hidden lines are parsed too.
+///
+/// ```
+/// # fn helper() -> u32 { 1 }
+/// let x = helper();
+/// ```
+fn hidden() {}
+
+/// Ignored without a reason.
+///
+/// ```ignore
+/// let db = connect();
+/// ```
+fn ignored() {}
+
+/// Ignored with a reason.
+///
+/// ```ignore (needs a database)
+/// let db = connect(;
+/// ```
+fn ignored_with_reason() {}
+
+/// Not Rust.
+///
+/// ```text
+/// let x = ;
+/// ```
+fn text() {}
+
+/// Fails to compile on purpose.
+///
+/// ```compile_fail
+/// let x = ;
+/// ```
+fn compile_fail() {}
+
+/// Never closed.
+///
+/// ```
+/// let x = 1;
+fn unclosed() {}
+
+fn main() {}
@@ -0,0 +1,57 @@
+#![warn(double_lock, guard_across_blocking_call)]
+#![allow(dead_code, unused_variables)]
+
+use std::cell::RefCell;
+use std::sync::{Mutex, RwLock};
+use std::sync::mpsc::Receiver;
+use std::thread;
+use std::time::Duration;
+
+fn relock(m: &Mutex<Vec<u32>>) {
+    let guard = m.lock().unwrap();
+    let len = m.lock().unwrap().len();
+}
+
+fn relock_in_loop(m: &Mutex<Vec<u32>>) {
+    while let Some(x) = m.lock().unwrap().pop() {
+        m.lock().unwrap().push(x + 1);
+    }
+}
+
+fn refcell(c: &RefCell<Vec<u32>>) {
+    let first = c.borrow();
+    c.borrow_mut().push(1);
+}
+
+fn rwlock_reads(l: &RwLock<u32>) {
+    let a = l.read().unwrap();
+    let b = l.read().unwrap();
+}
+
+fn sleep_with_guard(m: &Mutex<u32>) {
+    let guard = m.lock().unwrap();
+    thread::sleep(Duration::from_millis(10));
+}
+
+fn recv_with_guard(m: &Mutex<u32>, rx: &Receiver<u32>) {
+    let mut guard = m.lock().unwrap();
+    *guard += rx.recv().unwrap();
+}
+
+fn dropped_before(m: &Mutex<u32>, rx: &Receiver<u32>) {
+    let guard = m.lock().unwrap();
+    drop(guard);
+    let x = rx.recv().unwrap();
+    *m.lock().unwrap() += x;
+}
+
+fn scoped(m: &Mutex<u32>) {
+    {
+        let mut guard = m.lock().unwrap();
+        *guard += 1;
+    }
+    *m.lock().unwrap() += 1;
+    thread::sleep(Duration::from_millis(10));
+}
+
+fn main() {}
@@ -0,0 +1,99 @@
+#![warn(missing_errors_doc, missing_panics_doc, missing_safety_doc)]
+#![allow(dead_code)]
+
+use std::io;
+
+/// Parses a number.
+pub fn parse(s: &str) -> Result<u32, String> {
+    s.parse().map_err(|_| s.to_owned())
+}
+
+/// Reads a file.
+pub fn read() -> io::Result<String> {
+    Ok(String::new())
+}
+
+/// Parses a number.
+///
+/// # Errors
+///
+/// Returns the input if it is not a number.
+pub fn documented_errors(s: &str) -> Result<u32, String> {
+    s.parse().map_err(|_| s.to_owned())
+}
+
+/// Returns the value.
+pub fn value(x: Option<u32>) -> u32 {
+    x.expect("a value")
+}
+
+/// Returns the value.
+pub fn explicit_panic(x: u32) -> u32 {
+    if x == 0 {
+        panic!("zero");
+    }
+    x
+}
+
+/// Returns the value.
+///
+/// # Panics
+///
+/// Panics if `x` is `None`.
+pub fn documented_panics(x: Option<u32>) -> u32 {
+    x.unwrap()
+}
+
+/// Returns the first element, indexing is not checked.
+pub fn first(v: &[u32]) -> u32 {
+    v[0]
+}
+
+/// Reads the value.
+pub unsafe fn read_raw(p: *const u32) -> u32 {
+    *p
+}
+
+/// Reads the value.
+///
+/// # Safety
+///
+/// `p` must be valid.
+pub unsafe fn documented_safety(p: *const u32) -> u32 {
+    *p
+}
+
+/// A marker.
+pub unsafe trait Marker {}
+
+/// A marker.
+///
+/// # Safety
+///
+/// Only for `Copy` types.
+pub unsafe trait DocumentedMarker {}
+
+pub struct Buffer;
+
+impl Buffer {
+    /// Returns the size.
+    pub fn size(&self) -> Result<usize, ()> {
+        Ok(0)
+    }
+
+    fn private(&self) -> Result<usize, ()> {
+        Ok(0)
+    }
+}
+
+impl Clone for Buffer {
+    fn clone(&self) -> Self {
+        Buffer
+    }
+}
+
+fn private(x: Option<u32>) -> Result<u32, ()> {
+    Ok(x.unwrap())
+}
+
+fn main() {}
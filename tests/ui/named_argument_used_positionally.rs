@@ -0,0 +1,24 @@
+#![warn(named_argument_used_positionally)]
+
+use std::fmt::Write;
+
+fn main() {
+    let name = "Ferris";
+    let width = 10;
+
+    // these should be fine
+    println!("{name} is {age}", name = name, age = 3);
+    println!("{} is {age}", name, age = 3);
+    println!("{age} is {}", name, age = width);
+    println!("{:>width$}", name, width = width);
+    println!("{:.prec$}", 1.5, prec = 2);
+    println!("{{age}} {}", 3);
+    println!("{0} {0}", name);
+
+    // these should throw warnings
+    println!("{} is {}", name, age = 3);
+    print!("{} is {}", name = name, age = 3);
+    let _ = format!("{} is {}", name, age = 3);
+    let mut buf = String::new();
+    writeln!(buf, r"{} is {}", name, age = 3).unwrap();
+}
@@ -2,7 +2,7 @@
 
 
 #![warn(needless_pass_by_value)]
-#![allow(dead_code, single_match, if_let_redundant_pattern_matching, many_single_char_names, option_option)]
+#![allow(dead_code, single_match, if_let_redundant_pattern_matching, many_single_char_names, option_option, redundant_clone)]
 
 #![feature(collections_range)]
 
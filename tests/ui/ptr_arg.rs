@@ -1,4 +1,4 @@
-#![allow(unused, many_single_char_names)]
+#![allow(unused, many_single_char_names, redundant_clone)]
 #![warn(ptr_arg)]
 
 use std::borrow::Cow;
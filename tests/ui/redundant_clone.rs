@@ -0,0 +1,51 @@
+#![warn(redundant_clone)]
+#![allow(dead_code, clone_on_copy)]
+
+#[derive(Clone)]
+struct Alpha(Vec<u32>);
+
+fn consume(_: String) {}
+
+fn argument(s: String) {
+    consume(s.clone());
+}
+
+fn local() -> usize {
+    let v = vec![1, 2, 3];
+    let w = v.clone();
+    w.len()
+}
+
+fn user_type(a: Alpha) -> Alpha {
+    a.clone()
+}
+
+// the value is used after the clone
+fn used_after(s: String) -> usize {
+    consume(s.clone());
+    s.len()
+}
+
+fn in_loop(s: String) {
+    for _ in 0..3 {
+        consume(s.clone());
+    }
+}
+
+// the value is still borrowed
+fn borrowed(s: String) -> usize {
+    let r = &s;
+    consume(s.clone());
+    r.len()
+}
+
+// the value is not owned
+fn reference(a: &Alpha) -> Alpha {
+    a.clone()
+}
+
+fn copy(x: u32) -> u32 {
+    x.clone()
+}
+
+fn main() {}
@@ -2,11 +2,11 @@
 
 
 #![allow(unused)]
-#![warn(invalid_regex, trivial_regex, regex_macro)]
+#![warn(invalid_regex, trivial_regex, regex_macro, regex_creation_in_loops, regex_capture_index_out_of_bounds)]
 
 extern crate regex;
 
-use regex::{Regex, RegexSet, RegexBuilder};
+use regex::{Regex, RegexSet, RegexBuilder, RegexSetBuilder};
 use regex::bytes::{Regex as BRegex, RegexSet as BRegexSet, RegexBuilder as BRegexBuilder};
 
 const OPENING_PAREN: &str = "(";
@@ -85,7 +85,72 @@ fn trivial_regex() {
     let non_trivial_binary_builder = BRegexBuilder::new("foo|bar");
 }
 
+fn builder() {
+    // the letters are not literals when the case is ignored
+    let case_insensitive = RegexBuilder::new("^foobar$").case_insensitive(true).build();
+    let nest_limit = RegexBuilder::new("((a))").nest_limit(1).build();
+    let unicode_error = BRegexBuilder::new(r"\p{Greek}").unicode(false).build();
+    let unicode = BRegexBuilder::new(r"\p{Greek}").unicode(true).build();
+
+    let flag = std::env::args().count() > 1;
+    let not_constant = BRegexBuilder::new(r"\p{Greek}").unicode(flag).build();
+}
+
+fn sets() {
+    let vec = RegexSet::new(vec!["a+", "[z-a]"]);
+    let builder = RegexSetBuilder::new(&["a+", "(b"]).multi_line(true).build();
+}
+
+fn loops(lines: &[&str]) {
+    for line in lines {
+        let re = Regex::new("[0-9]+").unwrap();
+        let set = RegexSet::new(&["a+", "b+"]).unwrap();
+        let builder = RegexBuilder::new("a+").case_insensitive(true).build().unwrap();
+        let not_constant = Regex::new(line);
+        let in_closure = || Regex::new("a+");
+    }
+    let mut i = 0;
+    while i < lines.len() {
+        let re = BRegex::new("[0-9]+");
+        i += 1;
+    }
+    let outside = Regex::new("[0-9]+");
+}
+
+fn captures(text: &str) {
+    let re = Regex::new(r"(\d+)-(?P<day>\d+)").unwrap();
+    let caps = re.captures(text).unwrap();
+    let full = &caps[0];
+    let second = &caps[2];
+    let third = &caps[3];
+    let fourth = caps.get(4);
+    let named = &caps["day"];
+
+    if let Some(caps) = re.captures(text) {
+        let third = &caps[3];
+    }
+    for caps in re.captures_iter(text) {
+        let third = &caps[3];
+    }
+
+    let non_capturing = Regex::new(r"(?:a)(b)").unwrap();
+    let caps = non_capturing.captures(text).unwrap();
+    let second = &caps[2];
+
+    let chained = RegexBuilder::new(r"(a)").case_insensitive(true).build().unwrap();
+    let caps = chained.captures(text).unwrap();
+    let second = caps.get(2);
+
+    let unknown = Regex::new(text).unwrap();
+    let caps = unknown.captures(text).unwrap();
+    let third = &caps[3];
+}
+
 fn main() {
     syntax_error();
     trivial_regex();
+    builder();
+    sets();
+    loops(&[]);
+    captures("");
 }
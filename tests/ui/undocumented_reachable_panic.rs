@@ -0,0 +1,69 @@
+#![warn(undocumented_reachable_panic)]
+#![allow(dead_code)]
+
+/// Returns the first element.
+///
+/// # Panics
+///
+/// Panics if `v` is empty.
+pub fn documented(v: &[u32]) -> u32 {
+    v[0]
+}
+
+pub fn direct(x: Option<u32>) -> u32 {
+    x.unwrap()
+}
+
+pub fn through_calls(v: &[u32]) -> u32 {
+    helper(v)
+}
+
+fn helper(v: &[u32]) -> u32 {
+    last(v, v.len())
+}
+
+fn last(v: &[u32], len: usize) -> u32 {
+    v[len - 1]
+}
+
+pub fn in_closure(v: Vec<Option<u32>>) -> Vec<u32> {
+    v.into_iter().map(|x| x.expect("no value")).collect()
+}
+
+pub struct Parser;
+
+impl Parser {
+    pub fn parse(&self, s: &str) -> u32 {
+        self.digit(s)
+    }
+
+    fn digit(&self, s: &str) -> u32 {
+        match s {
+            "0" => 0,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub fn recursive(n: u32) -> u32 {
+    if n == 0 {
+        0
+    } else {
+        recursive(n - 1)
+    }
+}
+
+pub fn array() -> u32 {
+    let a = [1, 2, 3];
+    a[1]
+}
+
+pub fn asserts(x: u32) {
+    assert!(x > 0, "x must be positive");
+}
+
+fn private(x: Option<u32>) -> u32 {
+    x.unwrap()
+}
+
+fn main() {}
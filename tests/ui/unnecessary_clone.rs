@@ -1,5 +1,5 @@
 #![warn(clone_on_ref_ptr)]
-#![allow(unused)]
+#![allow(unused, redundant_clone)]
 
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -0,0 +1,69 @@
+#![warn(undocumented_unsafe_blocks, oversized_unsafe_block, oversized_unsafe_fn)]
+#![allow(dead_code, unused_unsafe)]
+
+static mut COUNTER: u32 = 0;
+
+fn undocumented(p: *const u32) -> u32 {
+    unsafe { *p }
+}
+
+fn documented(p: *const u32) -> u32 {
+    // SAFETY: the callers pass a valid pointer
+    unsafe { *p }
+}
+
+fn documented_on_several_lines(p: *const u32) -> u32 {
+    // SAFETY: the callers pass a valid pointer,
+    // which is not written to while the function runs
+    let x = unsafe { *p };
+    x + 1
+}
+
+fn documented_inline(p: *const u32) -> u32 {
+    let x = /* SAFETY: the callers pass a valid pointer */ unsafe { *p };
+    x + 1
+}
+
+fn separated_by_blank_line(p: *const u32) -> u32 {
+    // SAFETY: the callers pass a valid pointer
+
+    unsafe { *p }
+}
+
+fn oversized(v: &[u32]) -> u32 {
+    // SAFETY: the slice is not empty
+    unsafe {
+        let len = v.len();
+        let first = *v.as_ptr();
+        let last = v[len - 1];
+        let sum = first + last;
+        COUNTER += 1;
+        sum
+    }
+}
+
+fn small(v: &[u32]) -> u32 {
+    // SAFETY: the slice is not empty
+    unsafe {
+        let first = *v.as_ptr();
+        COUNTER += first;
+        first + 1
+    }
+}
+
+unsafe fn oversized_fn(p: *mut u32, v: &mut Vec<u32>) {
+    v.push(1);
+    v.push(2);
+    v.sort();
+    *p = v.len() as u32;
+    v.dedup();
+}
+
+unsafe fn contract_only(v: &mut Vec<u32>) {
+    v.push(1);
+    v.push(2);
+    v.sort();
+    v.dedup();
+}
+
+fn main() {}
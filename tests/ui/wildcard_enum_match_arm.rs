@@ -0,0 +1,55 @@
+#![warn(wildcard_enum_match_arm)]
+#![allow(unused)]
+
+use std::cmp::Ordering;
+use std::io::ErrorKind;
+
+#[derive(Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+    Rgb(u8, u8, u8),
+    Cmyk { c: u8, m: u8, y: u8, k: u8 },
+}
+
+fn is_red(color: Color) -> bool {
+    match color {
+        Color::Red => true,
+        _ => false,
+    }
+}
+
+fn is_black(color: Color) -> bool {
+    match color {
+        Color::Rgb(0, 0, 0) => true,
+        Color::Cmyk { k, .. } if k == 255 => true,
+        Color::Red | Color::Green | Color::Blue => false,
+        other => false,
+    }
+}
+
+// every variant is listed
+fn is_named(color: Color) -> bool {
+    match color {
+        Color::Red | Color::Green | Color::Blue => true,
+        Color::Rgb(..) | Color::Cmyk { .. } => false,
+    }
+}
+
+fn is_less(ordering: Ordering) -> bool {
+    match ordering {
+        Ordering::Less => true,
+        _ => false,
+    }
+}
+
+// `ErrorKind` has a hidden variant, more variants may be added to it
+fn is_not_found(kind: ErrorKind) -> bool {
+    match kind {
+        ErrorKind::NotFound => true,
+        _ => false,
+    }
+}
+
+fn main() {}
@@ -7,6 +7,8 @@ fn main() {
 
     // This should fail
     writeln!(&mut v, "");
+    let mut out = Vec::new();
+    writeln!(out, "");
 
     // These should be fine
     writeln!(&mut v);
@@ -46,6 +46,7 @@ def parse_lints(lints, filepath):
                     print "don't use `declare_lint!` in clippy, use `declare_clippy_lint!` instead"
                     sys.exit(42)
                 elif line.startswith("declare_clippy_lint!"):
+                    last_comment = []
                     comment = False
                     deprecated = False
                     clippy = True
@@ -57,6 +58,15 @@ def parse_lints(lints, filepath):
                 else:
                     last_comment = []
             if not comment:
+                # the documentation of `declare_clippy_lint!` is inside the macro
+                doc = line.strip()
+                if doc.startswith("/// "):
+                    last_comment.append(doc[4:] + "\n")
+                    continue
+                elif doc.startswith("///"):
+                    last_comment.append(doc[3:] + "\n")
+                    continue
+
                 m = lintname_re.search(line)
 
                 if m: